@@ -0,0 +1,145 @@
+// 월별 정산서 작성 벤치마크 (순차 / 병렬)
+// cargo run --release --example bench_months -- [월별 거래 수] [학생회 수]
+// 1. 월별 sheet 7개를 메모리에 작성
+// 2. 학생회마다 generate 전체 (sheet 작성, 감사, xlsx 압축과 저장)를 하나씩 / 동시에 (batch와 같이)
+// 병렬 작성의 이득은 CPU 코어 수에 따름 (코어 1개면 차이 없음, 월 sheet는 최대 7배)
+use std::error::Error;
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+
+use transaction_manager::format::Styles;
+use transaction_manager::generate::generate;
+use transaction_manager::models::budget::BudgetPlan;
+use transaction_manager::models::config::Config;
+use transaction_manager::models::data::{Data, DataBuilder, Date, Time};
+use transaction_manager::models::template::Templates;
+use transaction_manager::models::theme::Theme;
+use transaction_manager::summary::Summary;
+use transaction_manager::write_month::{month_sheets, month_sheets_concurrent, MonthContext};
+use transaction_manager::{Columns, InputLists};
+
+type MonthDataList = Vec<(u8, Vec<Data>)>;
+
+// 2학기 (6월 ~ 12월) 가상 거래 내역
+fn synthetic(rows: usize) -> Result<MonthDataList, Box<dyn Error>> {
+    let mut balance = 1_000_000u32;
+    let mut month_data_list = Vec::new();
+    for month in 6..=12u8 {
+        let mut data_list = Vec::with_capacity(rows);
+        for i in 0..rows {
+            let (cash_in, cash_out) = match i % 3 {
+                0 => (30_000 + (i % 7) as u32 * 1_000, 0),
+                _ => (0, 10_000 + (i % 5) as u32 * 500),
+            };
+            balance = balance + cash_in - cash_out.min(balance);
+            let data = DataBuilder::new()
+                .date(Date::new(&format!("2024.{month:02}.{:02}", i % 28 + 1)))
+                .time(Time::new(&format!("{:02}:{:02}:00", i % 24, i % 60)))
+                .counterparty(format!("거래처{}", i % 50))
+                .cash_in(cash_in)
+                .cash_out(cash_out)
+                .balance(balance)
+                .build()?;
+            data_list.push(data);
+        }
+        month_data_list.push((month, data_list));
+    }
+    Ok(month_data_list)
+}
+
+// 학생회별 설정 (임시 디렉터리에 저장)
+fn council(index: usize) -> (Config, std::path::PathBuf) {
+    let config = Config {
+        organization: format!("벤치마크 학생회 {index}"),
+        preserve_edits: false,
+        ..Config::default()
+    };
+    let location =
+        std::env::temp_dir().join(format!("bench_months_{}_{index}.xlsx", std::process::id()));
+    (config, location)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1);
+    let rows = match args.next() {
+        Some(rows) => rows.parse()?,
+        None => 5_000,
+    };
+    let councils = match args.next() {
+        Some(councils) => councils.parse()?,
+        None => 4,
+    };
+    let period = (2024, 2);
+    let plan = BudgetPlan::default();
+    let theme = Theme::default();
+    let month_data_list = synthetic(rows)?;
+    let summary = Summary::new(&month_data_list, &plan);
+    let context = Arc::new(MonthContext {
+        input_lists: InputLists::new(period, &plan)?,
+        columns: Columns::default(),
+        styles: Styles::new(&theme)?,
+        template: Templates::default().month,
+        print: theme.print.clone(),
+        organization: String::from("벤치마크"),
+    });
+
+    let start = Instant::now();
+    let sequential = month_sheets(&month_data_list, &summary, &context)?;
+    let sequential_time = start.elapsed();
+
+    let start = Instant::now();
+    let concurrent =
+        month_sheets_concurrent(&month_data_list, &summary, Arc::clone(&context)).await?;
+    let concurrent_time = start.elapsed();
+
+    // 병렬 작성도 월 순서 유지
+    let names = |worksheets: &[rust_xlsxwriter::Worksheet]| {
+        worksheets
+            .iter()
+            .map(|worksheet| worksheet.name())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(&sequential), names(&concurrent));
+
+    // 거래내역 순서 (최근 거래가 앞)
+    let mut table: Vec<Data> = month_data_list
+        .into_iter()
+        .flat_map(|(_, data_list)| data_list)
+        .collect();
+    table.reverse();
+
+    let start = Instant::now();
+    for index in 0..councils {
+        let (config, location) = council(index);
+        generate(&config, table.clone(), Some(&location)).await?;
+    }
+    let generate_sequential_time = start.elapsed();
+
+    let start = Instant::now();
+    let handles: Vec<_> = (0..councils)
+        .map(|index| {
+            let table = table.clone();
+            tokio::spawn(async move {
+                let (config, location) = council(index);
+                generate(&config, table, Some(&location))
+                    .await
+                    .map(|generated| generated.location)
+                    .map_err(|e| e.to_string())
+            })
+        })
+        .collect();
+    for handle in handles {
+        std::fs::remove_file(handle.await??)?;
+    }
+    let generate_concurrent_time = start.elapsed();
+
+    let cores = thread::available_parallelism().map_or(1, |cores| cores.get());
+    println!("{cores} cores, 7 months x {rows} rows");
+    println!("month sheets sequential: {sequential_time:?}");
+    println!("month sheets concurrent: {concurrent_time:?}");
+    println!("generate {councils} councils one by one: {generate_sequential_time:?}");
+    println!("generate {councils} councils at once:   {generate_concurrent_time:?}");
+    Ok(())
+}
@@ -0,0 +1,326 @@
+use crate::models::{
+    audit::{AnomalyConfig, CheckConfig},
+    data::{Data, VariantName},
+};
+
+// 의심 거래 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    Duplicate,
+    LargeWithdrawal,
+    RoundCash,
+    LateNight,
+    Holiday,
+}
+
+impl VariantName for Reason {
+    fn variant_name(&self) -> &'static str {
+        match self {
+            Reason::Duplicate => "반복 출금",
+            Reason::LargeWithdrawal => "고액 출금",
+            Reason::RoundCash => "현금 출금",
+            Reason::LateNight => "심야 거래",
+            Reason::Holiday => "휴일 지출",
+        }
+    }
+}
+
+impl Reason {
+    pub fn config<'a>(&self, config: &'a AnomalyConfig) -> &'a CheckConfig {
+        match self {
+            Reason::Duplicate => &config.duplicate,
+            Reason::LargeWithdrawal => &config.large_withdrawal,
+            Reason::RoundCash => &config.round_cash,
+            Reason::LateNight => &config.late_night,
+            Reason::Holiday => &config.holiday,
+        }
+    }
+}
+
+// 의심 거래 (index는 data_list에서의 위치)
+#[derive(Debug, Clone)]
+pub struct Anomaly {
+    pub index: usize,
+    pub reason: Reason,
+    pub message: String,
+}
+
+// 설정에서 켜진 사유로 거래 검사
+pub fn detect(data_list: &[Data], config: &AnomalyConfig) -> Vec<Anomaly> {
+    let withdrawals: Vec<u32> = data_list
+        .iter()
+        .map(|data| data.cash_out)
+        .filter(|&cash_out| cash_out > 0)
+        .collect();
+    let average = match withdrawals.len() {
+        0 => 0.0,
+        len => {
+            withdrawals
+                .iter()
+                .map(|&cash_out| cash_out as f64)
+                .sum::<f64>()
+                / len as f64
+        }
+    };
+
+    let mut anomalies = Vec::new();
+    for (index, data) in data_list.iter().enumerate() {
+        let mut report = |reason: Reason, message: String| {
+            if reason.config(config).enabled {
+                anomalies.push(Anomaly {
+                    index,
+                    reason,
+                    message,
+                });
+            }
+        };
+        let date = &data.date;
+        let counterparty = data.counterparty.as_deref().unwrap_or("");
+
+        if data.cash_out > 0 {
+            // 앞선 거래 중 같은 상대, 같은 금액
+            let duplicate = data_list[..index].iter().find(|other| {
+                let days = (date.days() - other.date.days()).abs();
+                !counterparty.is_empty()
+                    && other.counterparty.as_deref() == Some(counterparty)
+                    && other.cash_out == data.cash_out
+                    && days <= config.duplicate_days as i64
+            });
+            if let Some(other) = duplicate {
+                report(
+                    Reason::Duplicate,
+                    format!(
+                        "{date} {counterparty} {}원 출금이 {} 출금과 같습니다.",
+                        data.cash_out, other.date
+                    ),
+                );
+            }
+
+            if withdrawals.len() > 1 && data.cash_out as f64 >= average * config.large_ratio {
+                report(
+                    Reason::LargeWithdrawal,
+                    format!(
+                        "{date} {}원 출금이 회기 평균 출금액 {:.0}원의 {:.1}배입니다.",
+                        data.cash_out,
+                        average,
+                        data.cash_out as f64 / average
+                    ),
+                );
+            }
+
+            let cash = config.cash_keywords.is_empty()
+                || config
+                    .cash_keywords
+                    .iter()
+                    .any(|keyword| counterparty.contains(keyword.as_str()));
+            if cash
+                && config.round_unit > 0
+                && data.cash_out >= config.round_min
+                && data.cash_out % config.round_unit == 0
+            {
+                report(
+                    Reason::RoundCash,
+                    format!("{date} {counterparty} {}원 현금 출금입니다.", data.cash_out),
+                );
+            }
+
+            if is_holiday(data, &config.holidays) {
+                report(
+                    Reason::Holiday,
+                    format!(
+                        "{date} 주말 또는 공휴일에 {}원 지출되었습니다.",
+                        data.cash_out
+                    ),
+                );
+            }
+        }
+
+        let Some(time) = data.time else {
+            continue;
+        };
+        let hour = time.hour;
+        let late_night = match config.night_start > config.night_end {
+            true => hour >= config.night_start || hour < config.night_end,
+            false => hour >= config.night_start && hour < config.night_end,
+        };
+        if late_night {
+            report(
+                Reason::LateNight,
+                format!(
+                    "{date} {:02}:{:02}에 거래되었습니다.",
+                    time.hour, time.minute
+                ),
+            );
+        }
+    }
+    anomalies
+}
+
+// 주말 또는 설정된 공휴일
+fn is_holiday(data: &Data, holidays: &[String]) -> bool {
+    let date = &data.date;
+    let month_day = format!("{:02}.{:02}", date.month, date.day);
+    let full = format!("{}.{month_day}", date.year);
+    matches!(date.weekday(), 0 | 6)
+        || holidays
+            .iter()
+            .any(|holiday| *holiday == month_day || *holiday == full)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::data::{DataBuilder, Date, Time};
+
+    // 출금 거래 ("yyyy.mm.dd", "hh:mm:ss")
+    fn withdrawal(date: &str, time: &str, counterparty: &str, cash_out: u32) -> Data {
+        DataBuilder::new()
+            .date(Date::new(date))
+            .time(Time::new(time))
+            .counterparty(counterparty)
+            .cash_in(0)
+            .cash_out(cash_out)
+            .balance(0)
+            .build()
+            .unwrap()
+    }
+
+    // reason만 켠 설정
+    fn only(reason: Reason) -> AnomalyConfig {
+        let mut config = AnomalyConfig::default();
+        for check in [
+            &mut config.duplicate,
+            &mut config.large_withdrawal,
+            &mut config.round_cash,
+            &mut config.late_night,
+            &mut config.holiday,
+        ] {
+            check.enabled = false;
+        }
+        match reason {
+            Reason::Duplicate => config.duplicate.enabled = true,
+            Reason::LargeWithdrawal => config.large_withdrawal.enabled = true,
+            Reason::RoundCash => config.round_cash.enabled = true,
+            Reason::LateNight => config.late_night.enabled = true,
+            Reason::Holiday => config.holiday.enabled = true,
+        }
+        config
+    }
+
+    // 찾은 거래의 위치
+    fn found(data_list: &[Data], config: &AnomalyConfig) -> Vec<usize> {
+        detect(data_list, config)
+            .into_iter()
+            .map(|anomaly| anomaly.index)
+            .collect()
+    }
+
+    #[test]
+    fn duplicate_within_days() {
+        let config = only(Reason::Duplicate);
+        let data_list = [
+            withdrawal("2024.06.04", "10:00:00", "문구점", 12300),
+            withdrawal("2024.06.07", "10:00:00", "문구점", 12300), // 3일 (경계)
+            withdrawal("2024.06.11", "10:00:00", "문구점", 12300), // 4일
+            withdrawal("2024.06.11", "11:00:00", "문구점", 12400), // 다른 금액
+            withdrawal("2024.06.11", "12:00:00", "서점", 12300),   // 다른 상대
+        ];
+        assert_eq!(found(&data_list, &config), [1]);
+
+        // 거래 상대가 없으면 비교하지 않음
+        let data_list = [
+            withdrawal("2024.06.04", "10:00:00", "", 5000),
+            withdrawal("2024.06.04", "11:00:00", "", 5000),
+        ];
+        assert!(found(&data_list, &config).is_empty());
+    }
+
+    #[test]
+    fn duplicate_across_year() {
+        let data_list = [
+            withdrawal("2024.12.31", "10:00:00", "문구점", 5000),
+            withdrawal("2025.01.02", "10:00:00", "문구점", 5000),
+        ];
+        assert_eq!(found(&data_list, &only(Reason::Duplicate)), [1]);
+    }
+
+    #[test]
+    fn large_withdrawal_from_ratio() {
+        let config = only(Reason::LargeWithdrawal);
+        // 평균 1,800원의 정확히 5배
+        let mut data_list = vec![withdrawal("2024.06.04", "10:00:00", "문구점", 1000); 9];
+        data_list.push(withdrawal("2024.06.05", "10:00:00", "문구점", 9000));
+        assert_eq!(found(&data_list, &config), [9]);
+
+        // 평균의 5배 미만
+        data_list[9].cash_out = 8999;
+        assert!(found(&data_list, &config).is_empty());
+
+        // 출금이 하나뿐이면 비교하지 않음
+        let data_list = [withdrawal("2024.06.04", "10:00:00", "문구점", 1_000_000)];
+        assert!(found(&data_list, &config).is_empty());
+    }
+
+    #[test]
+    fn round_cash_withdrawal() {
+        let config = only(Reason::RoundCash);
+        let data_list = [
+            withdrawal("2024.06.04", "10:00:00", "ATM 출금", 50000), // 최소 금액 (경계)
+            withdrawal("2024.06.04", "10:00:00", "ATM 출금", 40000), // 최소 금액 미만
+            withdrawal("2024.06.04", "10:00:00", "ATM 출금", 55000), // 단위로 나누어떨어지지 않음
+            withdrawal("2024.06.04", "10:00:00", "문구점", 100000),  // 현금 출금 아님
+            withdrawal("2024.06.04", "10:00:00", "CD 현금", 100000),
+        ];
+        assert_eq!(found(&data_list, &config), [0, 4]);
+
+        // 거래 상대 목록이 비어 있으면 모든 출금
+        let config = AnomalyConfig {
+            cash_keywords: Vec::new(),
+            ..config
+        };
+        assert_eq!(found(&data_list, &config), [0, 3, 4]);
+    }
+
+    #[test]
+    fn late_night_boundaries() {
+        let config = only(Reason::LateNight);
+        let data_list = [
+            withdrawal("2024.06.04", "22:59:59", "문구점", 1000),
+            withdrawal("2024.06.04", "23:00:00", "문구점", 1000),
+            withdrawal("2024.06.05", "00:00:00", "문구점", 1000),
+            withdrawal("2024.06.05", "05:59:59", "문구점", 1000),
+            withdrawal("2024.06.05", "06:00:00", "문구점", 1000),
+        ];
+        assert_eq!(found(&data_list, &config), [1, 2, 3]);
+
+        // 자정을 넘지 않는 범위
+        let config = AnomalyConfig {
+            night_start: 0,
+            night_end: 6,
+            ..config
+        };
+        assert_eq!(found(&data_list, &config), [2, 3]);
+    }
+
+    #[test]
+    fn holiday_withdrawal() {
+        let config = only(Reason::Holiday);
+        let mut deposit = withdrawal("2024.06.08", "10:00:00", "홍길동", 0);
+        deposit.cash_in = 10000;
+        let data_list = [
+            withdrawal("2024.06.05", "10:00:00", "문구점", 1000), // 수요일
+            withdrawal("2024.06.06", "10:00:00", "문구점", 1000), // 현충일
+            withdrawal("2024.06.08", "10:00:00", "문구점", 1000), // 토요일
+            withdrawal("2024.06.09", "10:00:00", "문구점", 1000), // 일요일
+            deposit,                                              // 수입은 제외
+        ];
+        assert_eq!(found(&data_list, &config), [1, 2, 3]);
+
+        // 연도를 포함한 휴일
+        let config = AnomalyConfig {
+            holidays: vec!["2024.06.05".to_owned()],
+            ..config
+        };
+        assert_eq!(found(&data_list, &config), [0, 2, 3]);
+    }
+}
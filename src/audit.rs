@@ -0,0 +1,267 @@
+use std::cmp::Reverse;
+
+use crate::{
+    anomaly::{self, Reason},
+    models::{
+        audit::{AuditConfig, CheckConfig, Severity},
+        budget::BudgetPlan,
+        data::{BusinessType, Data, VariantName},
+    },
+    summary::Summary,
+    term_months,
+    write_budget::business_rows,
+    Columns,
+};
+
+// 감사 점검 항목
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    MissingReceipt,
+    Unclassified,
+    BalanceMismatch,
+    OutsideTerm,
+    Overspent,
+    Anomaly(Reason),
+}
+
+impl VariantName for Check {
+    fn variant_name(&self) -> &'static str {
+        match self {
+            Check::MissingReceipt => "영수증 누락",
+            Check::Unclassified => "사업구분 미정",
+            Check::BalanceMismatch => "잔액 불일치",
+            Check::OutsideTerm => "회기 외 거래",
+            Check::Overspent => "예산 초과",
+            Check::Anomaly(reason) => reason.variant_name(),
+        }
+    }
+}
+
+// 감사 의견 한 건 (sheet의 row, col 셀로 연결)
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub severity: Severity,
+    pub check: Check,
+    pub sheet: String,
+    pub row: u32,
+    pub col: u16,
+    pub message: String,
+}
+
+// 설정에서 켜진 점검을 실행, 심각한 의견부터 정렬
+pub fn run(
+    period: (u16, u8),
+    month_data_list: &[(u8, Vec<Data>)],
+    plan: &BudgetPlan,
+    summary: &Summary,
+    config: &AuditConfig,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut report = |check: Check,
+                      config: &CheckConfig,
+                      sheet: &str,
+                      (row, col): (u32, u16),
+                      message: String| {
+        if config.enabled {
+            findings.push(Finding {
+                severity: config.severity,
+                check,
+                sheet: sheet.to_owned(),
+                row,
+                col,
+                message,
+            });
+        }
+    };
+
+    let months = term_months(period.1);
+    // 잔고와 은행 잔액의 차이, 차이가 바뀐 거래만 보고 (한 번 어긋나면 뒤 거래도 모두 어긋남)
+    // 이월금이 없으면 기초 잔액을 알 수 없으므로 첫 거래의 차이부터 기준으로 삼음
+    let mut difference = plan.carry_over.map(|_| 0);
+    for (month, data_list) in month_data_list {
+        let sheet = format!("{month}월 정산서");
+        let balances = summary
+            .month(*month)
+            .map(|summary| summary.balances.as_slice())
+            .unwrap_or_default();
+
+        for (i, data) in data_list.iter().enumerate() {
+            let row = 6 + i as u32;
+            let date = &data.date;
+
+            if data.cash_out > 0 && data.receipt_num.as_deref().unwrap_or("").is_empty() {
+                report(
+                    Check::MissingReceipt,
+                    &config.missing_receipt,
+                    &sheet,
+                    (row, 0),
+                    format!("{date} 지출 {}원의 영수증 번호가 없습니다.", data.cash_out),
+                );
+            }
+
+            if data.business_type == BusinessType::Unclassified {
+                report(
+                    Check::Unclassified,
+                    &config.unclassified,
+                    &sheet,
+                    (row, 0),
+                    format!("{date} 거래의 사업구분이 정해지지 않았습니다."),
+                );
+            }
+
+            if let Some(&balance) = balances.get(i) {
+                let current = balance - data.balance as i64;
+                let previous = difference.replace(current);
+                if let Some(previous) = previous.filter(|&previous| previous != current) {
+                    report(
+                        Check::BalanceMismatch,
+                        &config.balance_mismatch,
+                        &sheet,
+                        (row, Columns::BALANCE),
+                        match previous {
+                            0 => format!(
+                                "{date} 거래 후 잔고 {balance}원이 은행 잔액 {}원과 다릅니다.",
+                                data.balance
+                            ),
+                            _ => format!(
+                                "{date} 거래에서 잔고와 은행 잔액의 차이가 {previous}원에서 {current}원으로 바뀌었습니다."
+                            ),
+                        },
+                    );
+                }
+            }
+
+            if data.date.year != period.0 || !months.contains(&data.date.month) {
+                report(
+                    Check::OutsideTerm,
+                    &config.outside_term,
+                    &sheet,
+                    (row, 0),
+                    format!(
+                        "{date} 거래가 {}년도 제{}회기 밖에 있습니다.",
+                        period.0, period.1
+                    ),
+                );
+            }
+        }
+    }
+
+    // 의심 거래 (월을 넘는 반복 출금도 찾도록 회기 전체를 검사)
+    let positions: Vec<(u8, u32)> = month_data_list
+        .iter()
+        .flat_map(|(month, data_list)| (0..data_list.len() as u32).map(move |i| (*month, i)))
+        .collect();
+    let data_list: Vec<Data> = month_data_list
+        .iter()
+        .flat_map(|(_, data_list)| data_list.iter().cloned())
+        .collect();
+    for anomaly in anomaly::detect(&data_list, &config.anomaly) {
+        let (month, i) = positions[anomaly.index];
+        report(
+            Check::Anomaly(anomaly.reason),
+            anomaly.reason.config(&config.anomaly),
+            &format!("{month}월 정산서"),
+            (6 + i, 0),
+            anomaly.message,
+        );
+    }
+
+    // 예산 집행 sheet의 사업 행
+    let execution_sheet = format!("{}년도 제{}회기 예산 집행", period.0, period.1);
+    for (idx, (business, _)) in business_rows(plan).into_iter().enumerate() {
+        let budget = business.current_total() as i64;
+        let spent = summary.spent(&business.business_type, &business.name);
+        if spent > budget {
+            report(
+                Check::Overspent,
+                &config.overspent,
+                &execution_sheet,
+                (3 + idx as u32, 4),
+                format!(
+                    "{} 집행액 {spent}원이 예산 {budget}원을 {}원 초과했습니다.",
+                    business.name,
+                    spent - budget
+                ),
+            );
+        }
+    }
+
+    findings.sort_by_key(|finding| Reverse(finding.severity));
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::data::{DataBuilder, Date};
+
+    // 6월 거래 (수입, 은행 잔액)
+    fn month(transactions: &[(u32, u32)]) -> Vec<(u8, Vec<Data>)> {
+        let data_list = transactions
+            .iter()
+            .enumerate()
+            .map(|(i, &(cash_in, balance))| {
+                DataBuilder::new()
+                    .date(Date {
+                        year: 2024,
+                        month: 6,
+                        day: i as u8 + 1,
+                    })
+                    .business_type(BusinessType::GeneralBusiness)
+                    .cash_in(cash_in)
+                    .cash_out(0)
+                    .balance(balance)
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+        vec![(6, data_list)]
+    }
+
+    fn mismatches(month_data_list: &[(u8, Vec<Data>)], carry_over: Option<u32>) -> Vec<Finding> {
+        let plan = BudgetPlan {
+            carry_over,
+            ..BudgetPlan::default()
+        };
+        let summary = Summary::new(month_data_list, &plan);
+        run(
+            (2024, 2),
+            month_data_list,
+            &plan,
+            &summary,
+            &AuditConfig::default(),
+        )
+        .into_iter()
+        .filter(|finding| finding.check == Check::BalanceMismatch)
+        .collect()
+    }
+
+    #[test]
+    fn reports_first_balance_divergence_only() {
+        // 두 번째 거래부터 은행 잔액이 50원 적음
+        let month_data_list = month(&[(100, 1100), (100, 1150), (100, 1250), (100, 1350)]);
+        let findings = mismatches(&month_data_list, Some(1000));
+        assert_eq!(findings.len(), 1, "{findings:?}");
+        assert_eq!(findings[0].row, 7);
+        assert!(findings[0]
+            .message
+            .contains("잔고 1200원이 은행 잔액 1150원과"));
+    }
+
+    #[test]
+    fn reports_each_change_of_difference() {
+        let month_data_list = month(&[(100, 1100), (100, 1150), (100, 1250), (100, 1400)]);
+        let findings = mismatches(&month_data_list, Some(1000));
+        assert_eq!(findings.len(), 2, "{findings:?}");
+        assert_eq!(findings[1].row, 9);
+        assert!(findings[1].message.contains("50원에서 0원으로"));
+    }
+
+    #[test]
+    fn unknown_opening_balance_is_not_a_mismatch() {
+        // 이월금이 없으면 은행 잔액과의 일정한 차이는 기초 잔액
+        let month_data_list = month(&[(100, 1100), (100, 1200), (100, 1300)]);
+        assert!(mismatches(&month_data_list, None).is_empty());
+        assert_eq!(mismatches(&month_data_list, Some(0)).len(), 1);
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rust_xlsxwriter::{DocProperties, Workbook};
+
+use crate::{
+    extract_tables,
+    format::Styles,
+    generate::{generate, Generated},
+    merge_tables,
+    models::{
+        config::Config,
+        registry::{Organization, Registry},
+        theme::Theme,
+    },
+    notify::{notifiers, notify_all, Notice},
+    print_setup, validate,
+    write_consolidated::consolidated,
+};
+
+// 작성한 workbook, 검증 결과와 알림 결과
+#[derive(Debug)]
+pub struct Council {
+    pub generated: Generated,
+    pub validation: validate::Report,
+    pub notifications: Vec<(&'static str, Result<(), String>)>,
+}
+
+// 학생회별 작성 결과 (실패해도 나머지 학생회는 계속 작성)
+#[derive(Debug)]
+pub struct Outcome {
+    pub name: String,
+    pub result: Result<Council, String>,
+}
+
+// 일괄 작성 보고서 (registry 순서)
+#[derive(Debug)]
+pub struct Report {
+    pub outcomes: Vec<Outcome>,
+}
+
+impl Report {
+    pub fn passed(&self) -> bool {
+        self.outcomes.iter().all(|outcome| outcome.result.is_ok())
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for outcome in &self.outcomes {
+            match &outcome.result {
+                Ok(council) => {
+                    let generated = &council.generated;
+                    write!(
+                        f,
+                        "[OK]   {}: {} ({} findings, validation {}",
+                        outcome.name,
+                        generated.location.display(),
+                        generated.findings.len(),
+                        if council.validation.passed() {
+                            "PASS"
+                        } else {
+                            "FAIL"
+                        }
+                    )?;
+                    if let Some(round_trip) = &generated.round_trip {
+                        write!(
+                            f,
+                            ", {} carried, {} vanished",
+                            round_trip.carried,
+                            round_trip.vanished.len()
+                        )?;
+                    }
+                    let sent: Vec<&str> = council
+                        .notifications
+                        .iter()
+                        .filter(|(_, result)| result.is_ok())
+                        .map(|(name, _)| *name)
+                        .collect();
+                    if !sent.is_empty() {
+                        write!(f, ", sent via {}", sent.join(", "))?;
+                    }
+                    writeln!(f, ")")?;
+                    for (name, result) in &council.notifications {
+                        if let Err(e) = result {
+                            writeln!(f, "    - {name} failed: {e}")?;
+                        }
+                    }
+                }
+                Err(e) => writeln!(f, "[FAIL] {}: {e}", outcome.name)?,
+            }
+        }
+        let generated = self
+            .outcomes
+            .iter()
+            .filter(|outcome| outcome.result.is_ok())
+            .count();
+        writeln!(
+            f,
+            "Generated {generated} of {} workbooks",
+            self.outcomes.len()
+        )
+    }
+}
+
+// 학생회마다 workbook을 동시에 작성
+pub async fn run(registry: &Registry, config: &Config) -> Report {
+    let mut file_names = HashSet::new();
+    let mut handles = Vec::with_capacity(registry.organizations.len());
+    for organization in &registry.organizations {
+        // 같은 파일에 동시에 저장하지 않도록
+        if !file_names.insert(organization.file_name()) {
+            handles.push((
+                organization.name.clone(),
+                Err(format!("{} is already used", organization.file_name())),
+            ));
+            continue;
+        }
+
+        let name = organization.name.clone();
+        let location = registry.output_dir.join(organization.file_name());
+        let (organization, config) = (organization.clone(), config.clone());
+        let handle = tokio::spawn(async move {
+            council(&organization, config, &location)
+                .await
+                .map_err(|e| e.to_string())
+        });
+        handles.push((name, Ok(handle)));
+    }
+
+    let mut outcomes = Vec::with_capacity(handles.len());
+    for (name, handle) in handles {
+        let result = match handle {
+            Ok(handle) => match handle.await {
+                Ok(result) => result,
+                Err(e) => Err(format!("panicked: {e}")),
+            },
+            Err(e) => Err(e),
+        };
+        outcomes.push(Outcome { name, result });
+    }
+    Report { outcomes }
+}
+
+// 학생회 설정으로 거래내역을 합쳐 작성
+async fn council(
+    organization: &Organization,
+    config: Config,
+    location: &Path,
+) -> Result<Council, Box<dyn Error>> {
+    let mut config = match &organization.profile {
+        Some(profile) if !profile.exists() => {
+            return Err(format!("{}: profile not found", profile.display()).into())
+        }
+        Some(profile) => Config::load(profile)?,
+        None => config,
+    };
+    config.organization = organization.name.clone();
+    if let Some(budget_plan) = &organization.budget_plan {
+        config.budget_plan = budget_plan.clone();
+    }
+
+    let tables = organization
+        .statements
+        .iter()
+        .map(|statement| {
+            extract_tables(statement).map_err(|e| format!("{}: {e}", statement.display()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let table = merge_tables(tables);
+    if table.is_empty() {
+        return Err("no transactions in statements".into());
+    }
+
+    if let Some(output_dir) = location.parent() {
+        fs::create_dir_all(output_dir)?;
+    }
+    let generated = generate(&config, table, Some(location)).await?;
+    // 수기 입력 전이므로 구조만 검증
+    let validation = validate::validate(&generated.location, validate::Scope::Structure)?;
+
+    // 알림이 실패해도 workbook은 작성된 것으로 봄
+    let notifications = match notifiers(&config).map_err(|e| e.to_string()) {
+        Ok(notifiers) => {
+            notify_all(&notifiers, &Notice::new(&config.organization, &generated)).await
+        }
+        Err(e) => vec![("notifiers", Err(e))],
+    };
+    Ok(Council {
+        generated,
+        validation,
+        notifications,
+    })
+}
+
+// 학생회별 결과를 한 sheet에 모은 종합 workbook 작성 (작성된 workbook이 없으면 None)
+// 기간은 처음 작성된 학생회 기준
+pub fn consolidate(
+    report: &Report,
+    registry: &Registry,
+    config: &Config,
+) -> Result<Option<PathBuf>, Box<dyn Error>> {
+    let Some(period) = report
+        .outcomes
+        .iter()
+        .find_map(|outcome| outcome.result.as_ref().ok())
+        .map(|council| council.generated.period)
+    else {
+        return Ok(None);
+    };
+    let theme = Theme::load(&config.theme)?;
+    let styles = Styles::new(&theme)?;
+
+    let title = format!("{} 중앙감사위원회_종합", period.0);
+    let location = registry
+        .summary
+        .clone()
+        .unwrap_or_else(|| registry.output_dir.join(format!("{title}.xlsx")));
+
+    let mut workbook = Workbook::new();
+    let worksheet = workbook
+        .add_worksheet()
+        .set_name(format!("{}년도 제{}회기 종합", period.0, period.1))?;
+    consolidated(worksheet, period, &report.outcomes, &styles)?;
+    print_setup(worksheet, &theme.print, "중앙감사위원회", None)?;
+
+    workbook.set_properties(&DocProperties::new().set_title(&title));
+    if let Some(output_dir) = location.parent() {
+        fs::create_dir_all(output_dir)?;
+    }
+    workbook.save(&location)?;
+    Ok(Some(location))
+}
@@ -0,0 +1,295 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::summary::Summary;
+
+// Discord 메시지 제한 (글자 수)
+pub const CONTENT_LIMIT: usize = 2000;
+pub const EMBED_COUNT_LIMIT: usize = 10;
+pub const TITLE_LIMIT: usize = 256;
+pub const DESCRIPTION_LIMIT: usize = 4096;
+pub const FIELD_COUNT_LIMIT: usize = 25;
+pub const FIELD_NAME_LIMIT: usize = 256;
+pub const FIELD_VALUE_LIMIT: usize = 1024;
+pub const FOOTER_LIMIT: usize = 2048;
+pub const EMBED_LIMIT: usize = 6000; // 제목, 설명, 필드, 바닥글 합계
+
+// embed 색 (기본 theme의 주황색 머리글)
+const COLOR: u32 = 0xFCD5B6;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Embed {
+    pub title: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    pub color: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<Field>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<Footer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Field {
+    pub name: String,
+    pub value: String,
+    pub inline: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Footer {
+    pub text: String,
+}
+
+impl Embed {
+    // 제한에 포함되는 글자 수
+    pub fn len(&self) -> usize {
+        self.title.chars().count()
+            + self.description.chars().count()
+            + self
+                .fields
+                .iter()
+                .map(|field| field.name.chars().count() + field.value.chars().count())
+                .sum::<usize>()
+            + self
+                .footer
+                .as_ref()
+                .map_or(0, |footer| footer.text.chars().count())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[derive(Default)]
+pub struct EmbedBuilder {
+    title: String,
+    description: String,
+    color: Option<u32>,
+    fields: Vec<Field>,
+    footer: Option<String>,
+}
+
+// build에서 Discord 제한에 맞게 자름
+impl EmbedBuilder {
+    pub fn new() -> Self {
+        EmbedBuilder::default()
+    }
+    pub fn title(&mut self, title: impl Into<String>) -> &mut Self {
+        self.title = title.into();
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.description = description.into();
+        self
+    }
+    pub fn color(&mut self, color: u32) -> &mut Self {
+        self.color = Some(color);
+        self
+    }
+    pub fn field(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+        inline: bool,
+    ) -> &mut Self {
+        self.fields.push(Field {
+            name: name.into(),
+            value: value.into(),
+            inline,
+        });
+        self
+    }
+    pub fn footer(&mut self, footer: impl Into<String>) -> &mut Self {
+        self.footer = Some(footer.into());
+        self
+    }
+    pub fn build(&self) -> Embed {
+        let mut fields: Vec<Field> = self
+            .fields
+            .iter()
+            .map(|field| Field {
+                name: truncate(&field.name, FIELD_NAME_LIMIT),
+                value: truncate(&field.value, FIELD_VALUE_LIMIT),
+                inline: field.inline,
+            })
+            .collect();
+        // 넘치는 필드는 빼고 마지막 필드에 빠진 개수만 표시
+        let mut omitted = 0;
+        if fields.len() > FIELD_COUNT_LIMIT {
+            omitted = fields.len() - (FIELD_COUNT_LIMIT - 1);
+            fields.truncate(FIELD_COUNT_LIMIT - 1);
+        }
+
+        let mut embed = Embed {
+            title: truncate(&self.title, TITLE_LIMIT),
+            description: truncate(&self.description, DESCRIPTION_LIMIT),
+            color: self.color.unwrap_or(COLOR),
+            fields,
+            footer: self.footer.as_ref().map(|text| Footer {
+                text: truncate(text, FOOTER_LIMIT),
+            }),
+        };
+
+        // 전체 글자 수: 개수 표시 자리를 남기고 뒤 필드부터 빼고, 그래도 넘치면 설명을 자름
+        let marker_len = |omitted: usize| match omitted {
+            0 => 0,
+            count => {
+                let field = omitted_field(count);
+                field.name.chars().count() + field.value.chars().count()
+            }
+        };
+        while embed.len() + marker_len(omitted) > EMBED_LIMIT && !embed.fields.is_empty() {
+            embed.fields.pop();
+            omitted += 1;
+        }
+        if omitted > 0 {
+            embed.fields.push(omitted_field(omitted));
+        }
+        if embed.len() > EMBED_LIMIT {
+            let room = EMBED_LIMIT - (embed.len() - embed.description.chars().count());
+            embed.description = truncate(&embed.description, room);
+        }
+        embed
+    }
+}
+
+fn omitted_field(count: usize) -> Field {
+    Field {
+        name: "…".to_owned(),
+        value: format!("외 {count}개 항목 생략"),
+        inline: false,
+    }
+}
+
+// 글자 수 제한 (넘치면 마지막 글자를 '…'로)
+pub fn truncate(text: &str, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text.to_owned();
+    }
+    let mut truncated: String = text.chars().take(limit.saturating_sub(1)).collect();
+    if limit > 0 {
+        truncated.push('…');
+    }
+    truncated
+}
+
+// 1234567 -> "1,234,567원"
+pub fn won(amount: i64) -> String {
+    let digits = amount.unsigned_abs().to_string();
+    let grouped = digits
+        .as_bytes()
+        .rchunks(3)
+        .rev()
+        .map(|chunk| std::str::from_utf8(chunk).expect("ascii digits"))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{}{grouped}원", if amount < 0 { "-" } else { "" })
+}
+
+// 회기 재정 요약 (기초 잔액, 수입, 지출, 기말 잔액, 월별 표, 미분류 거래, 감사 의견)
+pub fn term_summary(
+    organization: &str,
+    period: (u16, u8),
+    summary: &Summary,
+    findings: usize,
+) -> Embed {
+    let mut builder = EmbedBuilder::new();
+    builder
+        .title(format!("{}년도 제{}회기 재정 현황", period.0, period.1))
+        .description(organization)
+        .field("기초 잔액", won(summary.opening_balance), true)
+        .field("총수입", won(summary.income()), true)
+        .field("총지출", won(summary.expense()), true)
+        .field("기말 잔액", won(summary.balance()), true)
+        .field("미분류 거래", format!("{}건", summary.unclassified), true)
+        .field("감사 의견", format!("{findings}건"), true);
+
+    if !summary.months.is_empty() {
+        builder.field("월별 현황", month_table(summary, FIELD_VALUE_LIMIT), false);
+    }
+    builder.footer("중앙감사위원회 재정감사").build()
+}
+
+// 월별 수입, 지출, 잔액 (머리글 포함, 고정폭 글꼴 정렬)
+pub fn month_lines(summary: &Summary) -> Vec<String> {
+    let mut rows = vec![["월", "수입", "지출", "잔액"].map(str::to_owned)];
+    rows.extend(summary.months.iter().map(|month| {
+        [
+            format!("{}월", month.month),
+            won(month.income),
+            won(month.expense),
+            won(month.balance()),
+        ]
+    }));
+    let widths: Vec<usize> = (0..4)
+        .map(|col| rows.iter().map(|row| width(&row[col])).max().unwrap_or(0))
+        .collect();
+
+    // 월은 왼쪽, 금액은 오른쪽 정렬
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .enumerate()
+                .map(|(col, (text, &w))| {
+                    let pad = " ".repeat(w - width(text));
+                    match col {
+                        0 => format!("{text}{pad}"),
+                        _ => format!("{pad}{text}"),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+// 코드 블록 표 (limit 글자를 넘기 전까지 월을 추가)
+pub fn month_table(summary: &Summary, limit: usize) -> String {
+    let lines = month_lines(summary);
+    let close = "```";
+    let mut table = "```\n".to_owned();
+    for (i, line) in lines.iter().enumerate() {
+        let more = format!("… 외 {}개월\n", lines.len() - i);
+        if table.chars().count() + line.chars().count() + 1 + more.chars().count() + close.len()
+            > limit
+        {
+            table.push_str(&more);
+            break;
+        }
+        table.push_str(line);
+        table.push('\n');
+    }
+    table.push_str(close);
+    table
+}
+
+// 고정폭 글꼴에서의 너비 (한글은 두 칸)
+fn width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c {
+            '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7A3}' => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+// webhook payload_json (content, embeds)
+// embed는 최대 10개, 모든 embed의 글자 수 합계도 EMBED_LIMIT 이하
+pub fn payload(content: &str, embeds: &[Embed]) -> Value {
+    let mut total = 0;
+    let embeds: Vec<&Embed> = embeds
+        .iter()
+        .take(EMBED_COUNT_LIMIT)
+        .take_while(|embed| {
+            total += embed.len();
+            total <= EMBED_LIMIT
+        })
+        .collect();
+    json!({
+        "content": truncate(content, CONTENT_LIMIT),
+        "embeds": embeds,
+    })
+}
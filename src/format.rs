@@ -1,81 +1,229 @@
-use rust_xlsxwriter::{Color, Format, FormatAlign, FormatBorder};
+use std::collections::HashMap;
+use std::error::Error;
+
+use rust_xlsxwriter::{Color, Format, FormatAlign, FormatBorder, FormatUnderline};
+use serde::Deserialize;
+
+use crate::models::theme::{ResolvedFonts, Theme};
 
-pub const BG_LAVENDER: Color = Color::RGB(0xCCC1DE); // lavender
-pub const BG_GRAY: Color = Color::RGB(0xD8D8D8); // gray
-pub const BATANGCHE: &str = "Batangche";
 pub const NUM_FORMAT_STR: &str = "_-₩* #,##0_-;-₩* #,##0_-;_-₩* \" - \"_-;_-@";
 pub const DATE_FORMAT_STR: &str = "mm\"월\" dd\"일\"";
+pub const RATE_FORMAT_STR: &str = "0.0%";
 
-pub fn format_list(idx: usize) -> Format {
-    let FORMAT_LIST: [Format; 8] = [
-        // [0] month
-        Format::new()
-            .set_align(FormatAlign::VerticalCenter)
-            .set_background_color(BG_LAVENDER)
-            .set_border(FormatBorder::Thin)
-            .set_align(FormatAlign::Center)
-            .set_font_name(BATANGCHE)
-            .set_font_size(15)
+// 한글 문자 집합 (LibreOffice에서 한글 글꼴 대체에 사용)
+const HANGUL_CHARSET: u8 = 129;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Style {
+    Title,           // 월 제목
+    Schema,          // 머리글
+    Text,            // 글자
+    GrayText,        // 회색 글자
+    LavenderText,    // 보라색 글자
+    Number,          // 금액
+    GrayFormula,     // 회색 수식
+    LavenderFormula, // 보라색 수식
+    Total,           // 합계 수식
+    Rate,            // 집행률
+    Date,            // 날짜
+    Notice,          // 안내 문구
+    Input,           // 입력 칸
+    InputNumber,     // 금액 입력 칸
+    InputDate,       // 날짜 입력 칸
+    Heading,         // 예산안, 정산서 머리글
+    Banner,          // 주황색 제목 칸
+    BannerTitle,     // 제목 글꼴
+    BannerSubtitle,  // 학생회명, 출범일 글꼴
+    Alert,           // 조건부 서식 강조
+    Link,            // sheet 내부 링크
+}
+
+const STYLES: [Style; 21] = [
+    Style::Title,
+    Style::Schema,
+    Style::Text,
+    Style::GrayText,
+    Style::LavenderText,
+    Style::Number,
+    Style::GrayFormula,
+    Style::LavenderFormula,
+    Style::Total,
+    Style::Rate,
+    Style::Date,
+    Style::Notice,
+    Style::Input,
+    Style::InputNumber,
+    Style::InputDate,
+    Style::Heading,
+    Style::Banner,
+    Style::BannerTitle,
+    Style::BannerSubtitle,
+    Style::Alert,
+    Style::Link,
+];
+
+// 테두리 변형 (기본 서식의 테두리를 변마다 바꿈)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Borders {
+    pub top: FormatBorder,
+    pub bottom: FormatBorder,
+    pub left: FormatBorder,
+    pub right: FormatBorder,
+}
+
+impl Borders {
+    pub const THIN: Borders = Borders::all(FormatBorder::Thin);
+    pub const MEDIUM: Borders = Borders::all(FormatBorder::Medium);
+
+    pub const fn all(border: FormatBorder) -> Borders {
+        Borders {
+            top: border,
+            bottom: border,
+            left: border,
+            right: border,
+        }
+    }
+    pub const fn top(self, top: FormatBorder) -> Borders {
+        Borders { top, ..self }
+    }
+    pub const fn bottom(self, bottom: FormatBorder) -> Borders {
+        Borders { bottom, ..self }
+    }
+    pub const fn left(self, left: FormatBorder) -> Borders {
+        Borders { left, ..self }
+    }
+    pub const fn right(self, right: FormatBorder) -> Borders {
+        Borders { right, ..self }
+    }
+
+    pub fn apply(&self, format: &Format) -> Format {
+        format
+            .clone()
+            .set_border_top(self.top)
+            .set_border_bottom(self.bottom)
+            .set_border_left(self.left)
+            .set_border_right(self.right)
+    }
+}
+
+// 서식 모음 (theme으로 한 번만 생성)
+#[derive(Debug, Clone)]
+pub struct Styles {
+    body: HashMap<Style, Format>, // 월별 정산서
+    form: HashMap<Style, Format>, // 예산안, 정산서 양식
+}
+
+impl Styles {
+    pub fn new(theme: &Theme) -> Result<Styles, Box<dyn Error>> {
+        let fonts = theme.resolve_fonts();
+        let mut body = HashMap::with_capacity(STYLES.len());
+        let mut form = HashMap::with_capacity(STYLES.len());
+        for style in STYLES {
+            body.insert(style, build(style, theme, &fonts, theme.font_size)?);
+            form.insert(style, build(style, theme, &fonts, theme.form_font_size)?);
+        }
+        Ok(Styles { body, form })
+    }
+
+    pub fn get(&self, style: Style) -> &Format {
+        &self.body[&style]
+    }
+
+    pub fn form(&self, style: Style) -> &Format {
+        &self.form[&style]
+    }
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Styles::new(&Theme::default()).expect("default theme")
+    }
+}
+
+// "#RRGGBB" -> Color
+pub fn parse_color(color: &str) -> Result<Color, Box<dyn Error>> {
+    let hex = color.trim_start_matches('#');
+    if hex.len() != 6 {
+        return Err(format!("invalid color: {color}").into());
+    }
+    Ok(Color::RGB(u32::from_str_radix(hex, 16)?))
+}
+
+fn build(
+    style: Style,
+    theme: &Theme,
+    fonts: &ResolvedFonts,
+    size: f64,
+) -> Result<Format, Box<dyn Error>> {
+    let lavender = parse_color(&theme.colors.lavender)?;
+    let gray = parse_color(&theme.colors.gray)?;
+
+    let cell = Format::new()
+        .set_align(FormatAlign::VerticalCenter)
+        .set_align(FormatAlign::Center)
+        .set_border(FormatBorder::Thin)
+        .set_font_name(&fonts.body)
+        .set_font_charset(HANGUL_CHARSET)
+        .set_font_size(size);
+    let number = cell.clone().set_font_name(&fonts.numbers);
+    let banner_font = Format::new()
+        .set_font_name(&fonts.title)
+        .set_font_charset(HANGUL_CHARSET)
+        .set_bold();
+
+    let format = match style {
+        Style::Title => cell
+            .set_font_name(&fonts.title)
+            .set_background_color(lavender)
+            .set_font_size(theme.title_font_size)
             .set_bold(),
-        // [1] schema
-        Format::new()
-            .set_align(FormatAlign::VerticalCenter)
-            .set_align(FormatAlign::Center)
-            .set_background_color(BG_LAVENDER)
-            .set_border(FormatBorder::Thin)
-            .set_font_name(BATANGCHE)
-            .set_font_size(10)
+        Style::Schema => cell.set_background_color(lavender).set_bold(),
+        Style::Text => cell,
+        Style::GrayText => cell.set_background_color(gray),
+        Style::LavenderText => cell.set_background_color(lavender),
+        Style::Number => number.set_num_format(NUM_FORMAT_STR),
+        Style::GrayFormula => number
+            .set_background_color(gray)
+            .set_num_format(NUM_FORMAT_STR),
+        Style::LavenderFormula => number
+            .set_background_color(lavender)
+            .set_num_format(NUM_FORMAT_STR),
+        Style::Total => number
+            .set_background_color(lavender)
+            .set_num_format(NUM_FORMAT_STR)
             .set_bold(),
-        // [2] text
-        Format::new()
-            .set_align(FormatAlign::VerticalCenter)
+        Style::Rate => number
+            .set_background_color(gray)
+            .set_num_format(RATE_FORMAT_STR),
+        Style::Date => cell.set_num_format(DATE_FORMAT_STR),
+        Style::Notice => cell.set_bold().set_border(FormatBorder::Medium),
+        Style::Input => cell.set_unlocked(),
+        Style::InputNumber => number.set_num_format(NUM_FORMAT_STR).set_unlocked(),
+        Style::InputDate => cell.set_num_format(DATE_FORMAT_STR).set_unlocked(),
+        Style::Heading => Format::new()
             .set_align(FormatAlign::Center)
-            .set_border(FormatBorder::Thin)
-            .set_font_name(BATANGCHE)
-            .set_font_size(10),
-        // [3] gray text
-        Format::new()
             .set_align(FormatAlign::VerticalCenter)
+            .set_font_name(&fonts.body)
+            .set_font_charset(HANGUL_CHARSET)
+            .set_font_size(size)
+            .set_background_color(parse_color(&theme.colors.heading)?),
+        Style::Banner => Format::new()
             .set_align(FormatAlign::Center)
-            .set_background_color(BG_GRAY)
-            .set_border(FormatBorder::Thin)
-            .set_font_name(BATANGCHE)
-            .set_font_size(10),
-        // [4] lavender text
-        Format::new()
             .set_align(FormatAlign::VerticalCenter)
-            .set_align(FormatAlign::Center)
-            .set_background_color(BG_LAVENDER)
-            .set_border(FormatBorder::Thin)
-            .set_font_name(BATANGCHE)
-            .set_font_size(10),
-        // [5] num
-        Format::new()
-            .set_align(FormatAlign::VerticalCenter)
-            .set_align(FormatAlign::Center)
-            .set_border(FormatBorder::Thin)
-            .set_font_name(BATANGCHE)
-            .set_font_size(10)
-            .set_num_format(NUM_FORMAT_STR),
-        // [6] gray formula
-        Format::new()
-            .set_align(FormatAlign::VerticalCenter)
-            .set_align(FormatAlign::Center)
-            .set_background_color(BG_GRAY)
-            .set_border(FormatBorder::Thin)
-            .set_font_name(BATANGCHE)
-            .set_font_size(10)
-            .set_num_format(NUM_FORMAT_STR),
-        // [7] lavender formula
-        Format::new()
-            .set_align(FormatAlign::VerticalCenter)
-            .set_align(FormatAlign::Center)
-            .set_background_color(BG_LAVENDER)
-            .set_border(FormatBorder::Thin)
-            .set_font_name(BATANGCHE)
-            .set_font_size(10)
-            .set_num_format(NUM_FORMAT_STR),
-    ];
-
-    FORMAT_LIST[idx].clone()
+            .set_font_name(&fonts.title)
+            .set_font_charset(HANGUL_CHARSET)
+            .set_background_color(parse_color(&theme.colors.banner)?)
+            .set_border(FormatBorder::Medium)
+            .set_unlocked(),
+        Style::BannerTitle => banner_font.set_font_size(30),
+        Style::BannerSubtitle => banner_font.set_font_size(16),
+        Style::Alert => Format::new()
+            .set_font_color(parse_color(&theme.colors.alert_font)?)
+            .set_background_color(parse_color(&theme.colors.alert_fill)?),
+        Style::Link => cell
+            .set_font_color(parse_color(&theme.colors.link)?)
+            .set_underline(FormatUnderline::Single),
+    };
+    Ok(format)
 }
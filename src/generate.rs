@@ -0,0 +1,279 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use rust_xlsxwriter::*;
+
+use crate::{
+    audit::{self, Finding},
+    define_names,
+    format::Styles,
+    models::{budget::BudgetPlan, config::Config, data::Data, template::Templates, theme::Theme},
+    print_setup, protect_sheet,
+    round_trip::{self, RoundTrip},
+    separate_data,
+    summary::Summary,
+    write_account::account,
+    write_audit::audit_opinion,
+    write_budget::budget,
+    write_dashboard::dashboard,
+    write_execution::execution,
+    write_ledger::ledger,
+    write_month::{month_sheets_concurrent, MonthContext},
+    Columns, InputLists,
+};
+
+// 작성한 workbook과 계산 결과
+#[derive(Debug)]
+pub struct Generated {
+    pub location: PathBuf,
+    pub period: (u16, u8),
+    pub summary: Summary,
+    pub findings: Vec<Finding>,
+    pub round_trip: Option<RoundTrip>, // 기존 workbook의 수기 입력을 유지한 경우
+}
+
+// output이 없으면 "{연도} 중앙감사위원회_재정감사.xlsx"에 작성
+pub async fn generate(
+    config: &Config,
+    table: Vec<Data>,
+    output: Option<&Path>,
+) -> Result<Generated, Box<dyn Error>> {
+    // 예산안 입력 파일이 없으면 빈 예산안 작성
+    let plan = if config.budget_plan.exists() {
+        BudgetPlan::from_file(&config.budget_plan)?
+    } else {
+        BudgetPlan::default()
+    };
+
+    let mut month_data_list = separate_data(table)?;
+    let period = term_period(&month_data_list)?;
+    let location = output.map_or_else(|| PathBuf::from(file_name(period)), Path::to_path_buf);
+
+    // 기존 workbook의 수기 입력 (사업구분, 사업명, 비고, 영수증번호) 유지
+    let round_trip = if config.preserve_edits && location.exists() {
+        let rows = round_trip::read_rows(&location)?;
+        Some(round_trip::carry_forward(&mut month_data_list, rows))
+    } else {
+        None
+    };
+
+    let (mut workbook, summary, findings) = build(config, &plan, &month_data_list, period).await?;
+
+    // Save the file to disk.
+    workbook.save(&location)?;
+
+    Ok(Generated {
+        location,
+        period,
+        summary,
+        findings,
+        round_trip,
+    })
+}
+
+// 파일로 저장하지 않고 메모리에 작성 (server mode)
+// location은 내려받을 파일 이름
+pub async fn generate_to_buffer(
+    config: &Config,
+    plan: &BudgetPlan,
+    table: Vec<Data>,
+) -> Result<(Generated, Vec<u8>), Box<dyn Error>> {
+    let month_data_list = separate_data(table)?;
+    let period = term_period(&month_data_list)?;
+    let (mut workbook, summary, findings) = build(config, plan, &month_data_list, period).await?;
+    let buffer = workbook.save_to_buffer()?;
+
+    Ok((
+        Generated {
+            location: PathBuf::from(file_name(period)),
+            period,
+            summary,
+            findings,
+            round_trip: None,
+        },
+        buffer,
+    ))
+}
+
+// workbook 없이 회기 요약과 감사 의견만 계산 (server mode 요약)
+// location은 workbook을 작성했다면 쓸 파일 이름
+pub fn summarize(
+    config: &Config,
+    plan: &BudgetPlan,
+    table: Vec<Data>,
+) -> Result<Generated, Box<dyn Error>> {
+    let month_data_list = separate_data(table)?;
+    let period = term_period(&month_data_list)?;
+    let summary = Summary::new(&month_data_list, plan);
+    let findings = audit::run(period, &month_data_list, plan, &summary, &config.audit);
+
+    Ok(Generated {
+        location: PathBuf::from(file_name(period)),
+        period,
+        summary,
+        findings,
+        round_trip: None,
+    })
+}
+
+// 첫 거래의 연도와 회기 (1월에 시작하면 제1회기, 6월에 시작하면 제2회기)
+fn term_period(month_data_list: &[(u8, Vec<Data>)]) -> Result<(u16, u8), Box<dyn Error>> {
+    let first = month_data_list
+        .iter()
+        .find_map(|(_, data_list)| data_list.first())
+        .ok_or("no transactions")?;
+    let term = match first.date.month {
+        1 => 1,
+        6 => 2,
+        month => {
+            return Err(format!(
+                "transactions start in {}.{month:02}, but a term starts in January or June",
+                first.date.year
+            )
+            .into())
+        }
+    };
+    Ok((first.date.year, term))
+}
+
+fn title(period: (u16, u8)) -> String {
+    format!("{} 중앙감사위원회_재정감사", period.0)
+}
+
+fn file_name(period: (u16, u8)) -> String {
+    format!("{}.xlsx", title(period))
+}
+
+// 모든 sheet 작성
+async fn build(
+    config: &Config,
+    plan: &BudgetPlan,
+    month_data_list: &[(u8, Vec<Data>)],
+    period: (u16, u8),
+) -> Result<(Workbook, Summary, Vec<Finding>), Box<dyn Error>> {
+    let theme = Theme::load(&config.theme)?;
+    let styles = Styles::new(&theme)?;
+    let templates = Templates::load(&config.templates)?;
+
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    let input_lists = InputLists::new(period, plan)?;
+    let summary = Summary::new(month_data_list, plan);
+    let columns = Columns {
+        bank_balance: config.bank_balance,
+    };
+    let context = Arc::new(MonthContext {
+        input_lists,
+        columns,
+        styles: styles.clone(),
+        template: templates.month.clone(),
+        print: theme.print.clone(),
+        organization: config.organization.clone(),
+    });
+    let worksheets =
+        month_sheets_concurrent(month_data_list, &summary, Arc::clone(&context)).await?;
+
+    // {}년도 제{}회기 예산안
+    let worksheet1 = workbook
+        .add_worksheet()
+        .set_name(format!("{}년도 제{}회기 예산안", period.0, period.1))?;
+
+    // budget
+    budget(
+        worksheet1,
+        period,
+        plan,
+        &summary,
+        &config.organization,
+        &templates.budget,
+        &styles,
+    )?;
+
+    // {}년도 제{}회기 정산서
+    let worksheet2 = workbook
+        .add_worksheet()
+        .set_name(format!("{}년도 제{}회기 정산서", period.0, period.1))?;
+
+    // account
+    account(
+        worksheet2,
+        period,
+        &summary,
+        config.bank_balance,
+        &config.organization,
+        &templates.account,
+        &styles,
+    )?;
+
+    // {}년도 제{}회기 예산 집행
+    let worksheet3 = workbook
+        .add_worksheet()
+        .set_name(format!("{}년도 제{}회기 예산 집행", period.0, period.1))?;
+
+    // execution
+    execution(worksheet3, period, plan, &summary, &styles)?;
+    context.input_lists.write_name_list(worksheet3)?;
+
+    // {}년도 제{}회기 대시보드
+    let worksheet4 = workbook
+        .add_worksheet()
+        .set_name(format!("{}년도 제{}회기 대시보드", period.0, period.1))?;
+
+    // dashboard
+    dashboard(worksheet4, period, &summary, &styles)?;
+
+    // {}년도 제{}회기 사업별 장부
+    if config.ledger {
+        let worksheet5 = workbook
+            .add_worksheet()
+            .set_name(format!("{}년도 제{}회기 사업별 장부", period.0, period.1))?;
+
+        ledger(worksheet5, period, month_data_list, &styles)?;
+    }
+
+    // {}년도 제{}회기 감사 의견
+    let findings = audit::run(period, month_data_list, plan, &summary, &config.audit);
+    let worksheet6 = workbook
+        .add_worksheet()
+        .set_name(format!("{}년도 제{}회기 감사 의견", period.0, period.1))?;
+
+    // audit opinion
+    audit_opinion(worksheet6, period, &findings, &styles)?;
+
+    // 예산안, 정산서 등 요약 sheet 인쇄 설정
+    for worksheet in workbook.worksheets_mut() {
+        print_setup(worksheet, &theme.print, &config.organization, None)?;
+    }
+
+    for worksheet in worksheets.into_iter() {
+        workbook.push_worksheet(worksheet);
+    }
+
+    // 월별 요약 셀, 기초 잔액, 사업별 예산
+    define_names(
+        &mut workbook,
+        period,
+        month_data_list,
+        plan,
+        &templates,
+        columns,
+    )?;
+
+    // 색칠된 칸(수식, 양식) 보호
+    for worksheet in workbook.worksheets_mut() {
+        protect_sheet(worksheet, config.password.as_deref());
+    }
+
+    workbook.set_properties(
+        &DocProperties::new()
+            .set_author("위형수")
+            .set_manager("위형수")
+            .set_title(title(period))
+            .set_company("Seoultech_com")
+            .set_comment("위형수, 여기에 잠들다..."),
+    );
+
+    Ok((workbook, summary, findings))
+}
@@ -1,23 +1,54 @@
+pub mod anomaly;
+pub mod audit;
+pub mod batch;
 pub mod discord_message;
 pub mod format;
+pub mod generate;
 pub mod models;
+pub mod notify;
+pub mod round_trip;
 pub mod send_file;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod summary;
+pub mod validate;
 pub mod write_account;
+pub mod write_audit;
 pub mod write_budget;
-
-use format::{format_list, DATE_FORMAT_STR, NUM_FORMAT_STR};
-use models::data::{Data, DataBuilder, Date, VariantName};
+pub mod write_consolidated;
+pub mod write_dashboard;
+pub mod write_execution;
+pub mod write_ledger;
+pub mod write_month;
+pub mod write_template;
+
+use format::{Borders, Style, Styles};
+use models::budget::BudgetPlan;
+use models::data::{Data, DataBuilder, Date, Time, VariantName};
+use models::template::{Template, Templates, Vars};
+use models::theme::Print;
 use regex::Regex;
-use rust_xlsxwriter::{ExcelDateTime, FormatBorder, Formula, Worksheet};
+use rust_xlsxwriter::{
+    ConditionalFormatFormula, DataValidation, DataValidationErrorStyle, ExcelDateTime,
+    FormatBorder, Formula, ProtectionOptions, Table, TableColumn, TableStyle, Workbook, Worksheet,
+};
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::error::Error;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
+use std::ops::RangeInclusive;
 use std::path::Path;
+use summary::MonthSummary;
+use write_budget::{business_rows, BUSINESS_TYPES};
 
 pub fn extract_tables(file_path: &Path) -> Result<Vec<Data>, Box<dyn Error>> {
     let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
+    read_tables(BufReader::new(file))
+}
+
+// 거래내역 읽기 (파일 또는 업로드한 내용)
+pub fn read_tables(reader: impl BufRead) -> Result<Vec<Data>, Box<dyn Error>> {
     let mut table: Vec<_> = Vec::new();
 
     // "1 yyyy.mm.dd hh:mm:ss name 000,000 000,000 000,000 (name) info info (info)"
@@ -40,6 +71,8 @@ pub fn regex_match(regex: &Regex, line: &str) -> Result<Option<Data>, Box<dyn Er
         let mut data_builder = DataBuilder::new();
         let data = data_builder
             .date(Date::new(&caps[2]))
+            .time(Time::new(&caps[2]))
+            .counterparty(caps[3].trim())
             .cash_in(caps[5].replace(",", "").parse()?)
             .cash_out(caps[4].replace(",", "").parse()?)
             .balance(caps[6].replace(",", "").parse()?)
@@ -51,11 +84,30 @@ pub fn regex_match(regex: &Regex, line: &str) -> Result<Option<Data>, Box<dyn Er
     }
 }
 
+// 여러 거래내역 합치기 (기간이 겹치면 같은 거래는 한 번만 포함, 최근 거래가 앞)
+pub fn merge_tables(tables: Vec<Vec<Data>>) -> Vec<Data> {
+    let mut table = Vec::new();
+    for data_list in tables {
+        let read = table.len();
+        for data in data_list {
+            if !table[..read].contains(&data) {
+                table.push(data);
+            }
+        }
+    }
+    table.sort_by_key(|data| Reverse(data.date.days()));
+    table
+}
+
 // 월별 데이터 분리
 pub fn separate_data(mut table: Vec<Data>) -> Result<Vec<(u8, Vec<Data>)>, Box<dyn Error>> {
     let mut month_data_list: HashMap<u8, Vec<Data>> = HashMap::new();
 
-    let first_month = table.last().expect("first month").date.month;
+    let first_month = table
+        .last()
+        .ok_or("거래내역에 거래가 없습니다.")?
+        .date
+        .month;
     let se: (u8, u8) = if first_month == 1 {
         (1, 6)
     } else if first_month == 6 {
@@ -82,6 +134,22 @@ pub fn separate_data(mut table: Vec<Data>) -> Result<Vec<(u8, Vec<Data>)>, Box<d
     Ok(month_data_list)
 }
 
+// 회기별 월 범위
+pub fn term_months(term: u8) -> RangeInclusive<u8> {
+    match term {
+        1 => 1..=6,
+        2 => 6..=12,
+        _ => 0..=0,
+    }
+}
+
+// 절대 참조 셀 이름 변환
+pub fn abs_cell_name(row: u32, col: u32) -> String {
+    let name = cell_name(row, col);
+    let idx = name.find(|c: char| c.is_ascii_digit()).unwrap();
+    format!("${}${}", &name[..idx], &name[idx..])
+}
+
 // 셀 이름 변환
 pub fn cell_name(row: u32, mut col: u32) -> String {
     let mut name = String::new();
@@ -96,206 +164,354 @@ pub fn cell_name(row: u32, mut col: u32) -> String {
     format!("{name}{}", row + 1)
 }
 
-// 월별 정산서 템플릿
-pub fn sheet_template(worksheet: &mut Worksheet, sheet_name: &str) -> Result<(), Box<dyn Error>> {
-    // sheet title
-    worksheet.set_name(sheet_name)?;
+// 수식 및 양식 보호, 입력 칸은 unlocked 서식으로 작성
+pub fn protect_sheet(worksheet: &mut Worksheet, password: Option<&str>) {
+    if let Some(password) = password {
+        worksheet.protect_with_password(password);
+    }
+    worksheet.protect_with_options(&ProtectionOptions {
+        format_columns: true,
+        format_rows: true,
+        sort: true,
+        use_autofilter: true,
+        ..ProtectionOptions::default()
+    });
+}
 
-    // set sheet cell width
-    worksheet
-        .set_column_width(0, 8.64)?
-        .set_column_width(1, 11.91)?
-        .set_column_width(2, 13.64)?
-        .set_column_width(3, 12)?
-        .set_column_width(4, 12)?
-        .set_column_width(5, 13)?
-        .set_column_width(6, 54.91)?
-        .set_column_width(7, 17.36)?;
-
-    // set sheet cell height
-    worksheet
-        .set_default_row_height(15)
-        .set_row_height(0, 21)?
-        .set_row_height(1, 21)?
-        .set_row_height(2, 21)?
-        .set_row_height(3, 21)?
-        .set_row_height(4, 15.5)?
-        .set_row_height(5, 15.8)?;
-
-    // set filter
-    worksheet.autofilter(4, 0, 4, 7)?;
-
-    // merge cells
+// 인쇄 설정, 월별 정산서는 마지막 행까지 인쇄 영역 지정
+pub fn print_setup(
+    worksheet: &mut Worksheet,
+    print: &Print,
+    organization: &str,
+    month_last_cell: Option<(u32, u16)>,
+) -> Result<(), Box<dyn Error>> {
+    // 머리글, 바닥글에서 &는 제어 문자
+    let organization = organization.replace('&', "&&");
+    let margins = &print.margins;
     worksheet
-        .merge_range(
-            0,
-            0,
-            3,
-            1,
-            &sheet_name.split(' ').next().unwrap(),
-            &format_list(0),
-        )?
-        .merge_range(0, 3, 0, 5, "금액", &format_list(1))?
-        .merge_range(0, 6, 0, 7, "비고", &format_list(1))?
-        .merge_range(1, 3, 1, 5, "=", &format_list(6))?
-        .merge_range(1, 6, 1, 7, "", &format_list(2))?
-        .merge_range(2, 3, 2, 5, "=", &format_list(6))?
-        .merge_range(2, 6, 2, 7, "", &format_list(2))?
-        .merge_range(3, 3, 3, 5, "=", &format_list(6))?
-        .merge_range(3, 6, 3, 7, "", &format_list(3))?
-        .merge_range(
-            4,
-            3,
-            4,
-            5,
-            "금액",
-            &format_list(1).clone().set_border_bottom(FormatBorder::None),
+        .set_paper_size(print.paper_size)
+        .set_margins(
+            margins.left,
+            margins.right,
+            margins.top,
+            margins.bottom,
+            margins.header,
+            margins.footer,
+        )
+        .set_header(print.header.replace("{organization}", &organization))
+        .set_footer(print.footer.replace("{organization}", &organization))
+        .set_print_center_horizontally(true);
+    if print.fit_to_width {
+        worksheet.set_print_fit_to_pages(1, 0);
+    }
+
+    if let Some((last_row, last_col)) = month_last_cell {
+        if print.landscape_months {
+            worksheet.set_landscape();
+        }
+        let (first, last) = print.repeat_rows;
+        worksheet
+            .set_repeat_rows(first, last)?
+            .set_print_area(0, 0, last_row, last_col)?;
+    }
+    Ok(())
+}
+
+// 월별 정산서 거래내역 표 머리글
+pub const TABLE_HEADERS: [&str; 8] = [
+    "날짜",
+    "사업구분",
+    "사업명",
+    "수입",
+    "지출",
+    "잔고",
+    "비고",
+    "영수증번호",
+];
+
+// 월별 정산서 열 배치 (은행 잔액 열을 넣으면 비고, 영수증번호가 한 칸씩 밀림)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Columns {
+    pub bank_balance: bool,
+}
+
+impl Columns {
+    pub const BALANCE: u16 = 5; // 잔고
+    pub const BANK_BALANCE: u16 = 6; // 은행 잔액
+
+    pub fn remarks(&self) -> u16 {
+        Columns::BANK_BALANCE + self.bank_balance as u16
+    }
+
+    pub fn receipt_num(&self) -> u16 {
+        self.remarks() + 1
+    }
+
+    pub fn headers(&self) -> Vec<&'static str> {
+        let mut headers = TABLE_HEADERS.to_vec();
+        if self.bank_balance {
+            headers.insert(Columns::BANK_BALANCE as usize, "은행 잔액");
+        }
+        headers
+    }
+}
+
+// 기초 잔액(예산안 이월금)의 정의된 이름
+pub const OPENING_BALANCE_NAME: &str = "기초잔액";
+// 예산안 학생회비 수입, 수입 계의 정의된 이름
+pub const DUES_INCOME_NAME: &str = "학생회비수입";
+pub const INCOME_TOTAL_NAME: &str = "수입계";
+
+// 월별 거래내역 표 이름
+pub fn table_name(month: u8) -> String {
+    format!("거래_{month}월")
+}
+
+// 월별 정산서 요약 셀의 정의된 이름 (수입, 지출, 이월금, 잔액)
+pub fn month_name(label: &str, month: u8) -> String {
+    format!("{label}_{month}월")
+}
+
+// 예산안 사업별 예산의 정의된 이름
+pub fn budget_name(idx: usize) -> String {
+    format!("사업예산_{}", idx + 1)
+}
+
+// 정의된 이름 작성 (셀 위치는 양식의 names)
+pub fn define_names(
+    workbook: &mut Workbook,
+    period: (u16, u8),
+    month_data_list: &[(u8, Vec<Data>)],
+    plan: &BudgetPlan,
+    templates: &Templates,
+    columns: Columns,
+) -> Result<(), Box<dyn Error>> {
+    let budget_sheet = format!("{}년도 제{}회기 예산안", period.0, period.1);
+    for (name, key) in [
+        (OPENING_BALANCE_NAME, "carry_over"),
+        (DUES_INCOME_NAME, "dues"),
+        (INCOME_TOTAL_NAME, "income"),
+    ] {
+        let (row, col) = templates.budget.named_cell(key, &Vars::new())?;
+        workbook.define_name(
+            name,
+            &format!("='{budget_sheet}'!{}", abs_cell_name(row, col as u32)),
         )?;
+    }
 
-    //
-    worksheet
-        .write_with_format(0, 2, "구분", &format_list(1))?
-        .write_with_format(1, 2, "수입", &format_list(1))?
-        .write_with_format(2, 2, "지출", &format_list(1))?
-        .write_with_format(3, 2, "이월금", &format_list(1))?
-        .write_with_format(
-            4,
-            0,
-            "날짜",
-            &format_list(1).clone().set_border_bottom(FormatBorder::None),
-        )?
-        .write_with_format(
-            4,
-            1,
-            "사업구분",
-            &format_list(1).clone().set_border_bottom(FormatBorder::None),
-        )?
-        .write_with_format(
-            4,
-            2,
-            "사업명",
-            &format_list(1).clone().set_border_bottom(FormatBorder::None),
-        )?
-        .write_with_format(
-            4,
-            6,
-            "비고",
-            &format_list(1).clone().set_border_bottom(FormatBorder::None),
-        )?
-        .write_with_format(
-            4,
-            7,
-            "영수증번호",
-            &format_list(1).clone().set_border_bottom(FormatBorder::None),
-        )?
-        .write_with_format(
-            5,
-            0,
-            "",
-            &format_list(1).clone().set_border_top(FormatBorder::None),
-        )?
-        .write_with_format(
-            5,
-            1,
-            "",
-            &format_list(1).clone().set_border_top(FormatBorder::None),
-        )?
-        .write_with_format(
-            5,
-            2,
-            "",
-            &format_list(1).clone().set_border_top(FormatBorder::None),
-        )?
-        .write_with_format(5, 3, "수입", &format_list(1))?
-        .write_with_format(5, 4, "지출", &format_list(1))?
-        .write_with_format(5, 5, "잔고", &format_list(1))?
-        .write_with_format(
-            5,
-            6,
-            "",
-            &format_list(1).clone().set_border_top(FormatBorder::None),
-        )?
-        .write_with_format(
-            5,
-            7,
-            "",
-            &format_list(1).clone().set_border_top(FormatBorder::None),
+    for (month, data_list) in month_data_list.iter() {
+        let sheet_name = format!("{month}월 정산서");
+        let vars = month_vars(&sheet_name, columns);
+        let mut cells = Vec::with_capacity(4);
+        for (label, key) in [
+            ("수입", "income"),
+            ("지출", "expense"),
+            ("이월금", "carry_over"),
+        ] {
+            cells.push((label, templates.month.named_cell(key, &vars)?));
+        }
+        cells.push((
+            "잔액",
+            (total_row(data_list.len() as u32), Columns::BALANCE),
+        ));
+        for (label, (row, col)) in cells {
+            workbook.define_name(
+                month_name(label, *month),
+                &format!("='{sheet_name}'!{}", abs_cell_name(row, col as u32)),
+            )?;
+        }
+    }
+
+    for (idx, (business, row)) in business_rows(plan).into_iter().enumerate() {
+        workbook.define_name(
+            budget_name(idx),
+            &format!(
+                "='{budget_sheet}'!{}:{}",
+                abs_cell_name(row, 5),
+                abs_cell_name(row + business.rows() - 1, 5)
+            ),
         )?;
+    }
 
     Ok(())
 }
 
+// 학생회비 납부 수입의 사업명 (예산안 수입 SUMIF 기준)
+pub const DUES_NAME: &str = "학생회비 납부";
+
+// 사업명 목록이 255자를 넘을 때 목록을 작성하는 예산 집행 sheet의 숨긴 열
+pub const NAME_LIST_COL: u16 = 10;
+
+// 월별 정산서 사업구분, 사업명 입력 목록
+pub struct InputLists {
+    business_type: DataValidation,
+    business_name: DataValidation,
+    name_list: Vec<String>, // 숨긴 열에 작성할 사업명 (목록을 직접 넣었으면 비어 있음)
+}
+
+impl InputLists {
+    pub fn new(period: (u16, u8), plan: &BudgetPlan) -> Result<InputLists, Box<dyn Error>> {
+        let business_type = DataValidation::new()
+            .allow_list_strings(
+                &BUSINESS_TYPES
+                    .iter()
+                    .map(|business_type| business_type.variant_name())
+                    .collect::<Vec<_>>(),
+            )?
+            .set_input_title("사업구분")?
+            .set_input_message("목록에서 사업구분을 선택하세요.")?
+            .set_error_title("사업구분 오류")?
+            .set_error_message("상시사업, 일반사업, 공약사업 중 하나를 선택하세요.")?;
+
+        let mut names = vec![DUES_NAME.to_owned()];
+        for business in plan.businesses.iter() {
+            if !names.contains(&business.name) {
+                names.push(business.name.clone());
+            }
+        }
+        // 목록이 255자를 넘으면 같은 목록을 예산 집행 sheet의 숨긴 열에 작성하고 참조
+        let (business_name, name_list) = match DataValidation::new().allow_list_strings(&names) {
+            Ok(validation) => (validation, Vec::new()),
+            Err(_) => (
+                DataValidation::new().allow_list_formula(Formula::new(format!(
+                    "='{}년도 제{}회기 예산 집행'!{}:{}",
+                    period.0,
+                    period.1,
+                    abs_cell_name(0, NAME_LIST_COL as u32),
+                    abs_cell_name(names.len() as u32 - 1, NAME_LIST_COL as u32)
+                ))),
+                names,
+            ),
+        };
+        let business_name = business_name
+            .set_input_title("사업명")?
+            .set_input_message("예산안의 사업명을 선택하세요.")?
+            .set_error_title("사업명 확인")?
+            .set_error_message("예산안에 없는 사업명입니다. 그대로 입력하시겠습니까?")?
+            .set_error_style(DataValidationErrorStyle::Warning);
+
+        Ok(InputLists {
+            business_type,
+            business_name,
+            name_list,
+        })
+    }
+
+    // 예산 집행 sheet에 사업명 목록 작성 (목록을 직접 넣었으면 작성하지 않음)
+    pub fn write_name_list(&self, worksheet: &mut Worksheet) -> Result<(), Box<dyn Error>> {
+        if self.name_list.is_empty() {
+            return Ok(());
+        }
+        worksheet
+            .write_column(0, NAME_LIST_COL, &self.name_list)?
+            .set_column_hidden(NAME_LIST_COL)?;
+        Ok(())
+    }
+}
+
+// 월별 정산서 템플릿
+pub fn sheet_template(
+    worksheet: &mut Worksheet,
+    sheet_name: &str,
+    template: &Template,
+    columns: Columns,
+    styles: &Styles,
+) -> Result<(), Box<dyn Error>> {
+    // sheet title
+    worksheet.set_name(sheet_name)?;
+
+    // 표 머리글(6행)은 write_data_in_sheet에서 표와 함께 작성
+    let vars = month_vars(sheet_name, columns);
+    write_template::render(worksheet, template, &vars, &HashMap::new(), styles)
+}
+
+// 월별 정산서 양식 변수
+pub fn month_vars(sheet_name: &str, columns: Columns) -> Vars {
+    let mut vars = Vars::new();
+    vars.set("title", sheet_name.split(' ').next().unwrap())
+        .set("remarks", columns.remarks())
+        .set("last", columns.receipt_num());
+    vars
+}
+
+// 월별 정산서 계 행 (거래, 입력 행 다음)
+pub fn total_row(len: u32) -> u32 {
+    7 + len
+}
+
+// previous: 앞 행 잔고 (첫 행은 이월금) 셀
 pub fn write_row_data(
     worksheet: &mut Worksheet,
     i: u32,
-    datetime: &ExcelDateTime,
+    previous: &str,
     data: &Data,
+    balance: i64,
+    columns: Columns,
+    styles: &Styles,
 ) -> Result<(), Box<dyn Error>> {
     // 날짜
+    let datetime = ExcelDateTime::from_ymd(data.date.year, data.date.month, data.date.day)?;
+    worksheet.write_with_format(6 + i, 0, &datetime, styles.get(Style::Date))?;
+
+    // 사업구분
     worksheet.write_with_format(
         6 + i,
-        0,
-        datetime,
-        &format_list(2).set_num_format(DATE_FORMAT_STR),
+        1,
+        data.business_type.variant_name(),
+        styles.get(Style::Input),
     )?;
 
-    // 사업구분
-    worksheet.write_with_format(6 + i, 1, data.business_type.variant_name(), &format_list(2))?;
-
     // 사업명
     worksheet.write_with_format(
         6 + i,
         2,
         data.business_name.clone().unwrap_or_default(),
-        &format_list(2),
+        styles.get(Style::Input),
     )?;
 
     // 수입
-    worksheet.write_with_format(
-        6 + i,
-        3,
-        data.cash_in,
-        &format_list(5).set_num_format(NUM_FORMAT_STR),
-    )?;
+    worksheet.write_with_format(6 + i, 3, data.cash_in, styles.get(Style::Number))?;
 
     // 지출
-    worksheet.write_with_format(
-        6 + i,
-        4,
-        data.cash_out,
-        &format_list(5).set_num_format(NUM_FORMAT_STR),
-    )?;
+    worksheet.write_with_format(6 + i, 4, data.cash_out, styles.get(Style::Number))?;
 
     // 잔고
     worksheet.write_formula_with_format(
         6 + i,
         5,
         Formula::new(format!(
-            "={}+{}-{}",
-            match i {
-                0 => cell_name(3, 3), // 이월금
-                _ => cell_name(5 + i, 5),
-            },
+            "={previous}+{}-{}",
             cell_name(6 + i, 3),
             cell_name(6 + i, 4)
-        )),
-        &format_list(6),
+        ))
+        .set_result(balance.to_string()),
+        styles.get(Style::GrayFormula),
     )?;
 
+    // 은행 잔액
+    if columns.bank_balance {
+        worksheet.write_with_format(
+            6 + i,
+            Columns::BANK_BALANCE,
+            data.balance,
+            styles.get(Style::Number),
+        )?;
+    }
+
     // 비고
     worksheet.write_with_format(
         6 + i,
-        6,
+        columns.remarks(),
         data.remarks.clone().unwrap_or_default(),
-        &format_list(2),
+        styles.get(Style::Input),
     )?;
 
     // 영수증번호
     worksheet.write_with_format(
         6 + i,
-        7,
+        columns.receipt_num(),
         data.receipt_num.clone().unwrap_or_default(),
-        &format_list(2),
+        styles.get(Style::Input),
     )?;
 
     Ok(())
@@ -304,43 +520,45 @@ pub fn write_row_data(
 pub fn write_data_in_sheet(
     worksheet: &mut Worksheet,
     data_list: &Vec<Data>,
+    summary: &MonthSummary,
+    input_lists: &InputLists,
+    columns: Columns,
+    styles: &Styles,
 ) -> Result<(), Box<dyn Error>> {
+    let month = summary.month;
+    // 앞 행 잔고, 첫 행은 양식의 이월금 셀 (정의된 이름)
+    let previous = |i: u32| match i {
+        0 => month_name("이월금", month),
+        _ => cell_name(5 + i, 5),
+    };
     for (i, data) in data_list.iter().enumerate() {
-        let datetime = ExcelDateTime::from_ymd(data.date.year, data.date.month, data.date.day)?;
-        write_row_data(worksheet, i as u32, &datetime, data)?;
+        write_row_data(
+            worksheet,
+            i as u32,
+            &previous(i as u32),
+            data,
+            summary.balances[i],
+            columns,
+            styles,
+        )?;
     }
 
     let len = data_list.len() as u32;
-    // 공백 열
+    // 공백 열 (직접 입력)
     // 날짜
-    worksheet.write_with_format(
-        6 + len,
-        0,
-        "",
-        &format_list(2).set_num_format(DATE_FORMAT_STR),
-    )?;
+    worksheet.write_with_format(6 + len, 0, "", styles.get(Style::InputDate))?;
 
     // 사업구분
-    worksheet.write_with_format(6 + len, 1, "", &format_list(2))?;
+    worksheet.write_with_format(6 + len, 1, "", styles.get(Style::Input))?;
 
     // 사업명
-    worksheet.write_with_format(6 + len, 2, "", &format_list(2))?;
+    worksheet.write_with_format(6 + len, 2, "", styles.get(Style::Input))?;
 
     // 수입
-    worksheet.write_with_format(
-        6 + len,
-        3,
-        "",
-        &format_list(5).set_num_format(NUM_FORMAT_STR),
-    )?;
+    worksheet.write_with_format(6 + len, 3, "", styles.get(Style::InputNumber))?;
 
     // 지출
-    worksheet.write_with_format(
-        6 + len,
-        4,
-        "",
-        &format_list(5).set_num_format(NUM_FORMAT_STR),
-    )?;
+    worksheet.write_with_format(6 + len, 4, "", styles.get(Style::InputNumber))?;
 
     // 잔고
     worksheet.write_formula_with_format(
@@ -348,53 +566,119 @@ pub fn write_data_in_sheet(
         5,
         Formula::new(format!(
             "={}+{}-{}",
-            match len {
-                0 => cell_name(3, 3), // 이월금
-                _ => cell_name(5 + len, 5),
-            },
+            previous(len),
             cell_name(6 + len, 3),
             cell_name(6 + len, 4)
-        )),
-        &format_list(6),
+        ))
+        .set_result(summary.balance().to_string()),
+        styles.get(Style::GrayFormula),
     )?;
 
+    // 은행 잔액
+    if columns.bank_balance {
+        worksheet.write_with_format(
+            6 + len,
+            Columns::BANK_BALANCE,
+            "",
+            styles.get(Style::InputNumber),
+        )?;
+
+        // 잔고와 은행 잔액이 다른 행 강조
+        let balance = cell_name(6, Columns::BALANCE.into());
+        let bank_balance = cell_name(6, Columns::BANK_BALANCE.into());
+        let rule = format!("=AND(${bank_balance}<>\"\",${balance}<>${bank_balance})");
+        worksheet.add_conditional_format(
+            6,
+            Columns::BALANCE,
+            6 + len,
+            Columns::BANK_BALANCE,
+            &ConditionalFormatFormula::new()
+                .set_rule(rule.as_str())
+                .set_format(styles.get(Style::Alert)),
+        )?;
+    }
+
     // 비고
-    worksheet.write_with_format(6 + len, 6, "", &format_list(2))?;
+    worksheet.write_with_format(6 + len, columns.remarks(), "", styles.get(Style::Input))?;
 
     // 영수증번호
-    worksheet.write_with_format(6 + len, 7, "", &format_list(2))?;
+    worksheet.write_with_format(6 + len, columns.receipt_num(), "", styles.get(Style::Input))?;
+
+    // 사업구분, 사업명 목록
+    worksheet
+        .add_data_validation(6, 1, 6 + len, 1, &input_lists.business_type)?
+        .add_data_validation(6, 2, 6 + len, 2, &input_lists.business_name)?;
+
+    // 거래내역 표 (머리글 + 거래 + 공백 열)
+    worksheet.add_table(
+        5,
+        0,
+        6 + len,
+        columns.receipt_num(),
+        &Table::new()
+            .set_name(table_name(month))
+            .set_style(TableStyle::None)
+            .set_columns(
+                &columns
+                    .headers()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(col, header)| {
+                        TableColumn::new().set_header(header).set_header_format(
+                            // 금액 열은 위쪽 "금액" 머리글과 테두리로 구분
+                            match (3..columns.remarks()).contains(&(col as u16)) {
+                                true => styles.get(Style::Schema).clone(),
+                                false => Borders::THIN
+                                    .top(FormatBorder::None)
+                                    .apply(styles.get(Style::Schema)),
+                            },
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+    )?;
 
     // 계
+    let total = total_row(len);
     worksheet
-        .merge_range(7 + len, 0, 7 + len, 2, "계", &format_list(4))?
+        .merge_range(total, 0, total, 2, "계", styles.get(Style::LavenderText))?
         .write_formula_with_format(
-            7 + len,
+            total,
             3,
-            Formula::new(format!(
-                "=SUM({}:{})",
-                cell_name(6, 3),
-                cell_name(6 + len, 3)
-            )),
-            &format_list(7).set_num_format(NUM_FORMAT_STR),
+            Formula::new(format!("=SUM({}[수입])", table_name(month)))
+                .set_result(summary.income.to_string()),
+            styles.get(Style::LavenderFormula),
         )?
         .write_formula_with_format(
-            7 + len,
+            total,
             4,
-            Formula::new(format!(
-                "=SUM({}:{})",
-                cell_name(6, 4),
-                cell_name(6 + len, 4)
-            )),
-            &format_list(7).set_num_format(NUM_FORMAT_STR),
+            Formula::new(format!("=SUM({}[지출])", table_name(month)))
+                .set_result(summary.expense.to_string()),
+            styles.get(Style::LavenderFormula),
         )?
         .write_formula_with_format(
-            7 + len,
+            total,
             5,
-            Formula::new(format!("={}", cell_name(6 + len, 5),)),
-            &format_list(7).set_num_format(NUM_FORMAT_STR),
+            Formula::new(format!("={}", cell_name(6 + len, 5)))
+                .set_result(summary.balance().to_string()),
+            styles.get(Style::LavenderFormula),
         )?
-        .write_with_format(7 + len, 6, "", &format_list(4))?
-        .write_with_format(7 + len, 7, "", &format_list(4))?;
+        .write_row_with_format(
+            total,
+            Columns::BANK_BALANCE,
+            vec![""; (columns.receipt_num() - Columns::BANK_BALANCE + 1) as usize],
+            styles.get(Style::LavenderText),
+        )?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separate_empty_table() {
+        assert!(separate_data(Vec::new()).is_err());
+    }
+}
@@ -1,135 +1,138 @@
-use rust_xlsxwriter::*;
+use std::env;
 use std::error::Error;
-use std::path::Path;
-use transaction_manager::format::format_list;
-use transaction_manager::write_account::account;
-use transaction_manager::write_budget::budget;
-use transaction_manager::{
-    cell_name, extract_tables, separate_data, sheet_template, write_data_in_sheet,
-};
+use std::path::{Path, PathBuf};
+use std::process;
+use transaction_manager::batch;
+use transaction_manager::extract_tables;
+use transaction_manager::generate::{generate, Generated};
+use transaction_manager::models::config::Config;
+use transaction_manager::models::registry::Registry;
+use transaction_manager::notify::{self, Notice};
+use transaction_manager::round_trip;
+#[cfg(feature = "server")]
+use transaction_manager::server;
+use transaction_manager::validate;
 
 // 월별 transaction 분류
 // 병렬로 sheet 작성
 // 이후 workbook에 sheet 추가
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
-    let file_path = Path::new("account.txt");
-    let table = extract_tables(file_path)?;
-
-    // Create a new Excel file object.
-    let mut workbook = Workbook::new();
-
-    let mut worksheets = Vec::with_capacity(7);
-    let month_data_list = separate_data(table)?;
-    let mut data_size = Vec::with_capacity(month_data_list.len()); // 작월 이월금을 가져오기 위해 사용
+const USAGE: &str = "usage: transaction_manager [update <workbook.xlsx> [statement] | validate <workbook.xlsx> | batch <registry.json> | serve [address]]";
 
-    //
-    let period: (u16, u8) = {
-        (
-            month_data_list[0].1[0].date.year,
-            match month_data_list[0].0 {
-                // 이 부분은 개선의 필요가 있을 듯
-                6 => 2,
-                1 => 1,
-                _ => 0,
-            },
-        )
-    };
+// server mode 기본 주소
+#[cfg(feature = "server")]
+const SERVER_ADDRESS: &str = "127.0.0.1:8080";
 
-    for (month, data_list) in month_data_list.iter() {
-        let sheet_name = month.to_string() + "월 정산서";
-        let mut worksheet = Worksheet::new();
-        sheet_template(&mut worksheet, sheet_name.as_str())?;
-
-        // write data
-        write_data_in_sheet(&mut worksheet, data_list)?;
-
-        // write schema formula
-        let len = data_list.len() as u32;
-        worksheet
-            // 수입
-            .write_formula_with_format(
-                1,
-                3,
-                Formula::new(format!("={}", cell_name(7 + len, 3))),
-                &format_list(6),
-            )?
-            // 지출
-            .write_formula_with_format(
-                2,
-                3,
-                Formula::new(format!("={}", cell_name(7 + len, 4))),
-                &format_list(6),
-            )?;
-        // 이월금
-        if worksheets.is_empty() {
-            worksheet
-                .write_formula_with_format(
-                    3,
-                    3,
-                    Formula::new(format!("='{}년도 제{}회기 예산안'!B7", period.0, period.1)),
-                    &format_list(6),
-                )?
-                .write_with_format(3, 6, "전단위 인수인계 금액", &format_list(3))?;
-        } else {
-            worksheet
-                .write_formula_with_format(
-                    3,
-                    3,
-                    Formula::new(format!(
-                        "='{}월 정산서'!{}",
-                        month - 1,
-                        cell_name(7 + data_size.last().unwrap(), 5)
-                    )),
-                    &format_list(6),
-                )?
-                .write_with_format(3, 6, format!("{}월 이월금", month - 1), &format_list(3))?;
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let config = Config::load(Path::new("config.json"))?;
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        None => {
+            let table = extract_tables(&config.statement)?;
+            let generated = generate(&config, table, None).await?;
+            print_generated(&generated);
+            send(&config, &generated).await
         }
-
-        data_size.push(data_list.len() as u32);
-        worksheets.push(worksheet);
+        // 기존 workbook에 처음 보는 거래만 추가 (분류는 유지)
+        Some("update") => {
+            let workbook = args.get(1).map(PathBuf::from).ok_or(USAGE)?;
+            let statement = args
+                .get(2)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| config.statement.clone());
+
+            let rows = round_trip::read_rows(&workbook)?;
+            let (table, appended) = round_trip::append_unseen(&rows, extract_tables(&statement)?)?;
+            println!(
+                "Appended {appended} new transactions to {}",
+                workbook.display()
+            );
+
+            // 기존 행의 수기 입력은 이미 거래에 포함됨
+            let config = Config {
+                preserve_edits: false,
+                ..config
+            };
+            let generated = generate(&config, table, Some(&workbook)).await?;
+            print_generated(&generated);
+            send(&config, &generated).await
+        }
+        // 제출된 workbook 검증, 실패하면 종료 코드 1
+        Some("validate") => {
+            let workbook = args.get(1).map(PathBuf::from).ok_or(USAGE)?;
+            let report = validate::validate(&workbook, validate::Scope::All)?;
+            print!("{report}");
+            if !report.passed() {
+                process::exit(1);
+            }
+            Ok(())
+        }
+        // 학생회별 workbook과 종합 workbook 일괄 작성, 하나라도 실패하면 종료 코드 1
+        Some("batch") => {
+            let registry = args.get(1).map(PathBuf::from).ok_or(USAGE)?;
+            let registry = Registry::load(&registry)?;
+            let report = batch::run(&registry, &config).await;
+            print!("{report}");
+            if let Some(location) = batch::consolidate(&report, &registry, &config)? {
+                println!("Completely generated {}", location.display());
+            }
+            if !report.passed() {
+                process::exit(1);
+            }
+            Ok(())
+        }
+        // 거래내역, 설정, 예산안을 업로드받아 workbook을 돌려주는 server
+        #[cfg(feature = "server")]
+        Some("serve") => {
+            let address = args.get(1).map_or(SERVER_ADDRESS, String::as_str);
+            let listener = tokio::net::TcpListener::bind(address).await?;
+            println!("Listening on http://{}", listener.local_addr()?);
+            server::serve(listener, config).await
+        }
+        Some(command) => Err(format!("unknown command: {command}\n{USAGE}").into()),
     }
+}
 
-    // {}년도 제{}회기 예산안
-    let worksheet1 = workbook
-        .add_worksheet()
-        .set_name(format!("{}년도 제{}회기 예산안", period.0, period.1))?;
-
-    // budget
-    budget(worksheet1, period)?;
-
-    // {}년도 제{}회기 정산서
-    let worksheet2 = workbook
-        .add_worksheet()
-        .set_name(format!("{}년도 제{}회기 정산서", period.0, period.1))?;
-
-    // account
-    account(worksheet2, period)?;
-
-    for worksheet in worksheets.into_iter() {
-        workbook.push_worksheet(worksheet);
+fn print_generated(generated: &Generated) {
+    if let Some(report) = &generated.round_trip {
+        println!("Carried manual edits of {} transactions", report.carried);
+        for row in &report.vanished {
+            println!(
+                "Vanished: '{}' row {} ({} 수입 {} 지출 {}){}",
+                row.sheet,
+                row.row + 1,
+                row.date,
+                row.cash_in,
+                row.cash_out,
+                if row.is_edited() {
+                    " - manual edits dropped"
+                } else {
+                    ""
+                }
+            );
+        }
     }
+    println!("Completely generated {}", generated.location.display());
+}
 
-    let title = format!("{} 중앙감사위원회_재정감사", period.0);
-    workbook.set_properties(
-        &DocProperties::new()
-            .set_author("위형수")
-            .set_manager("위형수")
-            .set_title(&title)
-            .set_company("Seoultech_com")
-            .set_comment("위형수, 여기에 잠들다..."),
-    );
-
-    // Save the file to disk.
-    let location = format!("{}.xlsx", &title);
-    let location = Path::new(&location);
-    workbook.save(&location)?;
-
-    // send xlsx file to discord server
-    // send_discord_xlsx(&location).await?;
-
-    println!("Completely generated {title}.xlsx");
-
+// 설정된 알림 (Discord, Slack, webhook, 전자우편)으로 전송
+async fn send(config: &Config, generated: &Generated) -> Result<(), Box<dyn Error>> {
+    let notifiers = notify::notifiers(config)?;
+    let notice = Notice::new(&config.organization, generated);
+    let mut failed = 0;
+    for (name, result) in notify::notify_all(&notifiers, &notice).await {
+        match result {
+            Ok(()) => println!("Sent {} via {name}", generated.location.display()),
+            Err(e) => {
+                eprintln!("Failed to notify via {name}: {e}");
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{failed} of {} notifications failed", notifiers.len()).into());
+    }
     Ok(())
 }
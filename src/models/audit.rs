@@ -0,0 +1,111 @@
+use serde::Deserialize;
+
+use super::data::VariantName;
+
+// 감사 의견 심각도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,     // 참고
+    Warning,  // 주의
+    Critical, // 심각
+}
+
+impl VariantName for Severity {
+    fn variant_name(&self) -> &'static str {
+        match self {
+            Severity::Info => "참고",
+            Severity::Warning => "주의",
+            Severity::Critical => "심각",
+        }
+    }
+}
+
+// 점검 항목별 설정
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct CheckConfig {
+    #[serde(default = "enabled")]
+    pub enabled: bool,
+    pub severity: Severity,
+}
+
+fn enabled() -> bool {
+    true
+}
+
+impl CheckConfig {
+    const fn new(severity: Severity) -> CheckConfig {
+        CheckConfig {
+            enabled: true,
+            severity,
+        }
+    }
+}
+
+// 감사 점검 설정 (config.json의 audit)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AuditConfig {
+    pub missing_receipt: CheckConfig,  // 영수증 번호 누락 (지출)
+    pub unclassified: CheckConfig,     // 사업구분 미정
+    pub balance_mismatch: CheckConfig, // 잔고와 은행 잔액 불일치
+    pub outside_term: CheckConfig,     // 회기 밖 거래
+    pub overspent: CheckConfig,        // 예산 초과 집행
+    pub anomaly: AnomalyConfig,        // 의심 거래
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        AuditConfig {
+            missing_receipt: CheckConfig::new(Severity::Warning),
+            unclassified: CheckConfig::new(Severity::Warning),
+            balance_mismatch: CheckConfig::new(Severity::Critical),
+            outside_term: CheckConfig::new(Severity::Warning),
+            overspent: CheckConfig::new(Severity::Critical),
+            anomaly: AnomalyConfig::default(),
+        }
+    }
+}
+
+// 의심 거래 탐지 설정
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AnomalyConfig {
+    pub duplicate: CheckConfig, // 같은 상대에게 같은 금액 반복 출금
+    pub duplicate_days: u32,    // 반복으로 보는 기간 (일)
+    pub large_withdrawal: CheckConfig,
+    pub large_ratio: f64, // 회기 평균 출금액의 몇 배 이상
+    pub round_cash: CheckConfig,
+    pub round_unit: u32,            // 이 단위로 나누어떨어지는 금액
+    pub round_min: u32,             // 최소 금액
+    pub cash_keywords: Vec<String>, // 현금 출금으로 보는 거래 상대 (비어 있으면 모든 출금)
+    pub late_night: CheckConfig,
+    pub night_start: u8, // 시
+    pub night_end: u8,   // 시
+    pub holiday: CheckConfig,
+    pub holidays: Vec<String>, // 주말 외 휴일 ("mm.dd" 또는 "yyyy.mm.dd")
+}
+
+impl Default for AnomalyConfig {
+    fn default() -> Self {
+        let list = |items: &[&str]| items.iter().map(|item| item.to_string()).collect();
+        AnomalyConfig {
+            duplicate: CheckConfig::new(Severity::Warning),
+            duplicate_days: 3,
+            large_withdrawal: CheckConfig::new(Severity::Warning),
+            large_ratio: 5.0,
+            round_cash: CheckConfig::new(Severity::Info),
+            round_unit: 10000,
+            round_min: 50000,
+            cash_keywords: list(&["ATM", "CD", "현금", "출금"]),
+            late_night: CheckConfig::new(Severity::Info),
+            night_start: 23,
+            night_end: 6,
+            holiday: CheckConfig::new(Severity::Info),
+            // 양력 공휴일 (설날, 추석 등 음력 공휴일은 설정에 추가)
+            holidays: list(&[
+                "01.01", "03.01", "05.05", "06.06", "08.15", "10.03", "10.09", "12.25",
+            ]),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use serde::Deserialize;
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use super::data::BusinessType;
+
+// 예산안 입력 파일
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BudgetPlan {
+    #[serde(default)]
+    pub carry_over: Option<u32>, // 이월금
+    #[serde(default)]
+    pub previous_income: Option<u32>, // 전년도 수입 계 (전년도 예비비 계산)
+    #[serde(default)]
+    pub businesses: Vec<Business>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Business {
+    pub business_type: BusinessType,
+    pub name: String,
+    #[serde(default)]
+    pub details: Vec<BudgetLine>,
+}
+
+// 지출 상세
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BudgetLine {
+    pub detail: String,
+    pub previous: u32, // 전년도 예산
+    pub current: u32,  // 올해 예산
+    pub basis: String, // 산출 근거
+    pub remarks: String,
+}
+
+impl BudgetPlan {
+    pub fn from_file(file_path: &Path) -> Result<BudgetPlan, Box<dyn Error>> {
+        let reader = BufReader::new(File::open(file_path)?);
+        let plan: BudgetPlan = serde_json::from_reader(reader)?;
+        plan.validate()?;
+        Ok(plan)
+    }
+
+    // 예산안에는 사업구분별 행만 있으므로 미정 사업은 작성할 수 없음
+    pub fn validate(&self) -> Result<(), Box<dyn Error>> {
+        match self
+            .businesses
+            .iter()
+            .find(|business| business.business_type == BusinessType::Unclassified)
+        {
+            Some(business) => Err(format!(
+                "사업 '{}'의 사업구분이 미정입니다. (상시사업, 일반사업, 공약사업 중 하나)",
+                business.name
+            )
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    pub fn businesses_of<'a>(
+        &'a self,
+        business_type: &'a BusinessType,
+    ) -> impl Iterator<Item = &'a Business> + 'a {
+        self.businesses
+            .iter()
+            .filter(move |business| &business.business_type == business_type)
+    }
+
+    pub fn previous_total(&self) -> u32 {
+        self.businesses
+            .iter()
+            .flat_map(|business| business.details.iter())
+            .map(|line| line.previous)
+            .sum()
+    }
+
+    pub fn current_total(&self) -> u32 {
+        self.businesses
+            .iter()
+            .map(|business| business.current_total())
+            .sum()
+    }
+}
+
+impl Business {
+    // 예산안에서 차지하는 행 수
+    pub fn rows(&self) -> u32 {
+        self.details.len().max(1) as u32
+    }
+
+    // 올해 예산 합계
+    pub fn current_total(&self) -> u32 {
+        self.details.iter().map(|line| line.current).sum()
+    }
+}
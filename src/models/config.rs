@@ -0,0 +1,58 @@
+use serde::Deserialize;
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use super::audit::AuditConfig;
+use super::discord::DiscordConfig;
+use super::notify::NotifierConfig;
+use super::template::TemplatePaths;
+
+// 실행 설정 (config.json)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub statement: PathBuf,             // 거래내역 파일
+    pub budget_plan: PathBuf,           // 예산안 입력 파일
+    pub ledger: bool,                   // 사업별 장부 sheet 작성 여부
+    pub bank_balance: bool,             // 월별 정산서 은행 잔액 열 작성 여부
+    pub password: Option<String>,       // sheet 보호 암호
+    pub theme: PathBuf,                 // 색상, 글꼴 설정 파일
+    pub organization: String,           // 학생회명 (예산안, 정산서 제목 및 인쇄 머리글)
+    pub templates: TemplatePaths,       // sheet 양식 파일 (없으면 기본 양식)
+    pub preserve_edits: bool,           // 기존 workbook의 수기 입력 유지
+    pub audit: AuditConfig,             // 감사 점검 항목
+    pub discord: DiscordConfig,         // 작성한 workbook을 Discord로 전송
+    pub notifiers: Vec<NotifierConfig>, // 그 밖의 알림 (Slack, webhook, 전자우편)
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            statement: PathBuf::from("account.txt"),
+            budget_plan: PathBuf::from("budget.json"),
+            ledger: false,
+            bank_balance: false,
+            password: None,
+            theme: PathBuf::from("theme.json"),
+            organization: "OOOO대학 OOOO학과 제OO대 OOOO학생회".to_owned(),
+            templates: TemplatePaths::default(),
+            preserve_edits: true,
+            audit: AuditConfig::default(),
+            discord: DiscordConfig::default(),
+            notifiers: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    // 설정 파일이 없으면 기본값 사용
+    pub fn load(file_path: &Path) -> Result<Config, Box<dyn Error>> {
+        if !file_path.exists() {
+            return Ok(Config::default());
+        }
+        let reader = BufReader::new(File::open(file_path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
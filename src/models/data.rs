@@ -1,14 +1,20 @@
 use regex::Regex;
+use serde::Deserialize;
+use std::fmt;
 
 pub trait VariantName {
     fn variant_name(&self) -> &'static str;
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub enum BusinessType {
-    Unclassified,    // 미정
+    #[serde(rename = "미정")]
+    Unclassified, // 미정
+    #[serde(rename = "일반사업")]
     GeneralBusiness, // 일반사업
+    #[serde(rename = "공약사업")]
     PledgedBusiness, // 공약사업
+    #[serde(rename = "상시사업")]
     OngoingBusiness, // 상시사업
 }
 
@@ -18,6 +24,27 @@ impl Default for BusinessType {
     }
 }
 
+impl BusinessType {
+    // 사업구분 이름 -> BusinessType (빈 칸, "미정"은 Unclassified)
+    pub fn from_name(name: &str) -> Option<BusinessType> {
+        match name.trim() {
+            "" | "미정" => Some(BusinessType::Unclassified),
+            "일반사업" => Some(BusinessType::GeneralBusiness),
+            "공약사업" => Some(BusinessType::PledgedBusiness),
+            "상시사업" => Some(BusinessType::OngoingBusiness),
+            _ => None,
+        }
+    }
+
+    // 표시 이름 (셀에는 미정을 빈 칸으로 작성하므로 variant_name과 구분)
+    pub fn label(&self) -> &'static str {
+        match self {
+            BusinessType::Unclassified => "미정",
+            _ => self.variant_name(),
+        }
+    }
+}
+
 impl VariantName for BusinessType {
     fn variant_name(&self) -> &'static str {
         match self {
@@ -49,11 +76,76 @@ impl Date {
             day: caps[3].parse().unwrap(),
         }
     }
+
+    // 1970.01.01부터 지난 일 수
+    pub fn days(&self) -> i64 {
+        let (month, day) = (self.month as i64, self.day as i64);
+        let year = self.year as i64 - (month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    // days()의 역변환
+    pub fn from_days(days: i64) -> Date {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + (month <= 2) as i64;
+        Date {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+
+    // 0: 일요일 ~ 6: 토요일
+    pub fn weekday(&self) -> u8 {
+        (self.days() + 4).rem_euclid(7) as u8
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{:02}.{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Time {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl Time {
+    pub fn new(time: &str) -> Time {
+        let caps = Regex::new(r"(\d{2}):(\d{2}):(\d{2})")
+            .unwrap()
+            .captures(time)
+            .unwrap();
+
+        Time {
+            hour: caps[1].parse().unwrap(),
+            minute: caps[2].parse().unwrap(),
+            second: caps[3].parse().unwrap(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Data {
     pub date: Date,
+    pub time: Option<Time>, // 거래내역에 없는 기존 workbook 행은 시각 없음
+    pub counterparty: Option<String>, // 거래 상대 (적요)
     pub business_type: BusinessType,
     pub business_name: Option<String>,
     pub cash_in: u32,
@@ -66,6 +158,8 @@ pub struct Data {
 #[derive(Default)]
 pub struct DataBuilder {
     date: Option<Date>,
+    time: Option<Time>,
+    counterparty: Option<String>,
     business_type: BusinessType,
     business_name: Option<String>,
     cash_in: Option<u32>,
@@ -83,6 +177,14 @@ impl DataBuilder {
         self.date = Some(date);
         self
     }
+    pub fn time(&mut self, time: Time) -> &mut Self {
+        self.time = Some(time);
+        self
+    }
+    pub fn counterparty(&mut self, counterparty: impl Into<String>) -> &mut Self {
+        self.counterparty = Some(counterparty.into());
+        self
+    }
     pub fn business_type(&mut self, business_type: BusinessType) -> &mut Self {
         self.business_type = business_type;
         self
@@ -118,6 +220,8 @@ impl DataBuilder {
         let balance = self.balance.ok_or("No BALANCE provided")?;
         Ok(Data {
             date,
+            time: self.time,
+            counterparty: self.counterparty.clone(),
             business_type: self.business_type.clone(),
             business_name: self.business_name.clone(),
             cash_in,
@@ -128,3 +232,54 @@ impl DataBuilder {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_since_epoch() {
+        assert_eq!(Date::new("1970.01.01").days(), 0);
+        assert_eq!(Date::new("1969.12.31").days(), -1);
+        assert_eq!(Date::new("2000.03.01").days(), 11017);
+        assert_eq!(Date::new("2024.06.01").days(), 19875);
+    }
+
+    #[test]
+    fn days_cross_month_and_year() {
+        let days = |date: &str| Date::new(date).days();
+        assert_eq!(days("2025.01.01") - days("2024.12.31"), 1);
+        assert_eq!(days("2024.03.01") - days("2024.02.28"), 2); // 윤년
+        assert_eq!(days("2023.03.01") - days("2023.02.28"), 1);
+        assert_eq!(days("2100.03.01") - days("2100.02.28"), 1); // 100년 단위는 평년
+        assert_eq!(days("2025.01.01") - days("2024.01.01"), 366);
+    }
+
+    #[test]
+    fn from_days_inverts_days() {
+        for date in [
+            "1970.01.01",
+            "2000.02.29",
+            "2023.12.31",
+            "2024.01.01",
+            "2024.02.29",
+            "2024.12.31",
+            "2100.03.01",
+        ] {
+            assert_eq!(Date::from_days(Date::new(date).days()), Date::new(date));
+        }
+        let start = Date::new("2023.12.25").days();
+        for days in start..start + 800 {
+            assert_eq!(Date::from_days(days).days(), days);
+        }
+    }
+
+    #[test]
+    fn weekday_from_sunday() {
+        assert_eq!(Date::new("1970.01.01").weekday(), 4); // 목요일
+        assert_eq!(Date::new("2024.06.01").weekday(), 6); // 토요일
+        assert_eq!(Date::new("2024.06.02").weekday(), 0); // 일요일
+        assert_eq!(Date::new("2024.12.31").weekday(), 2);
+        assert_eq!(Date::new("2025.01.01").weekday(), 3);
+    }
+}
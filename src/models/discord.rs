@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+// Discord webhook 전송 설정
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DiscordConfig {
+    pub webhook_url: Option<String>, // 없으면 DISCORD_WEBHOOK_URL 환경 변수, 둘 다 없으면 전송하지 않음
+    pub max_retries: u32,            // 429, 5xx 응답과 연결 오류 재시도 횟수
+}
+
+impl Default for DiscordConfig {
+    fn default() -> Self {
+        DiscordConfig {
+            webhook_url: None,
+            max_retries: 3,
+        }
+    }
+}
@@ -1 +1,9 @@
+pub mod audit;
+pub mod budget;
+pub mod config;
 pub mod data;
+pub mod discord;
+pub mod notify;
+pub mod registry;
+pub mod template;
+pub mod theme;
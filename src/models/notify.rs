@@ -0,0 +1,64 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use super::discord::DiscordConfig;
+
+// 알림 설정 (config.json의 notifiers, "type"으로 구분)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NotifierConfig {
+    Discord(DiscordConfig), // webhook_url 필요 (DISCORD_WEBHOOK_URL은 config의 discord에만 사용)
+    Slack(SlackConfig),
+    Webhook(WebhookConfig),
+    Email(EmailConfig),
+}
+
+// Slack 호환 incoming webhook (파일은 첨부하지 않음)
+#[derive(Debug, Clone, Deserialize)]
+pub struct SlackConfig {
+    pub webhook_url: String,
+    #[serde(default = "max_retries")]
+    pub max_retries: u32,
+}
+
+// 회기 요약을 JSON으로 POST
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookConfig {
+    pub url: String,
+    #[serde(default)]
+    pub headers: HashMap<String, String>, // 인증 머리글 등
+    #[serde(default = "max_retries")]
+    pub max_retries: u32,
+}
+
+// SMTP 전자우편 (workbook 첨부)
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmailConfig {
+    pub host: String,
+    #[serde(default = "smtp_port")]
+    pub port: u16,
+    #[serde(default)]
+    pub security: Security,
+    pub username: Option<String>,
+    pub password: Option<String>, // 없으면 SMTP_PASSWORD 환경 변수
+    pub from: String,
+    pub to: Vec<String>,
+}
+
+// SMTP 연결 보안
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Security {
+    None, // 암호화 없음 (내부 중계 서버)
+    #[default]
+    StartTls, // 587
+    Tls,  // 465
+}
+
+fn max_retries() -> u32 {
+    3
+}
+
+fn smtp_port() -> u16 {
+    587
+}
@@ -0,0 +1,49 @@
+use serde::Deserialize;
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+// 일괄 작성할 학생회 목록 (batch 명령)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Registry {
+    #[serde(default = "output_dir")]
+    pub output_dir: PathBuf, // workbook 저장 폴더
+    pub summary: Option<PathBuf>, // 종합 workbook (없으면 output_dir의 "{연도} 중앙감사위원회_종합.xlsx")
+    pub organizations: Vec<Organization>,
+}
+
+fn output_dir() -> PathBuf {
+    PathBuf::from(".")
+}
+
+// 학생회별 입력 파일
+#[derive(Debug, Clone, Deserialize)]
+pub struct Organization {
+    pub name: String,                 // 학생회명 (config의 organization 대신 사용)
+    pub profile: Option<PathBuf>,     // 설정 파일 (config.json 형식, 없으면 config.json)
+    pub statements: Vec<PathBuf>,     // 거래내역 파일 (여러 개면 합쳐서 작성)
+    pub budget_plan: Option<PathBuf>, // 예산안 입력 파일 (없으면 설정의 budget_plan)
+}
+
+impl Registry {
+    pub fn load(file_path: &Path) -> Result<Registry, Box<dyn Error>> {
+        let reader = BufReader::new(File::open(file_path)?);
+        serde_json::from_reader(reader).map_err(|e| format!("{}: {e}", file_path.display()).into())
+    }
+}
+
+impl Organization {
+    // workbook 파일 이름 (경로에 쓸 수 없는 문자는 '_')
+    pub fn file_name(&self) -> String {
+        let name: String = self
+            .name
+            .chars()
+            .map(|c| match c {
+                '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+                c => c,
+            })
+            .collect();
+        format!("{}.xlsx", name.trim())
+    }
+}
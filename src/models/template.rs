@@ -0,0 +1,277 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use crate::format::Style;
+
+// 기본 양식 (templates/*.json)
+const MONTH: &str = include_str!("../../templates/month.json");
+const BUDGET: &str = include_str!("../../templates/budget.json");
+const ACCOUNT: &str = include_str!("../../templates/account.json");
+
+// sheet 양식 (셀, 병합, 너비, 높이, 서식, 자리 표시자, 반복 행)
+// 작성 순서: 열 너비 -> 반복 행 -> 행 높이 -> 병합 -> 셀
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Template {
+    pub form: bool, // 예산안, 정산서 양식 서식 사용 (false면 월별 정산서 서식)
+    pub columns: Vec<Column>,
+    pub default_row_height: Option<f64>,
+    pub rows: Vec<Row>,
+    pub blocks: Vec<Block>,
+    pub merges: Vec<Merge>,
+    pub cells: Vec<Cell>,
+    pub names: HashMap<String, [Pos; 2]>, // 정의된 이름, 수식이 참조하는 셀 ("income": [1, 3])
+}
+
+// 행, 열 위치: 숫자 또는 변수 식 ("remarks-1", "months_end+1")
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Pos {
+    Index(u32),
+    Expr(String),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Column {
+    pub col: Pos,
+    pub width: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Row {
+    pub row: Pos,
+    pub height: f64,
+}
+
+// 항목마다 반복되는 행 (항목 변수와 시작 행 {row} 사용)
+// 작성 후 {이름_end}에 다음 행 번호 저장
+#[derive(Debug, Clone, Deserialize)]
+pub struct Block {
+    pub name: String,
+    pub row: Pos,
+    #[serde(default = "one")]
+    pub rows: u32, // 항목당 행 수
+    pub height: Option<f64>,
+    #[serde(default)]
+    pub merges: Vec<Merge>,
+    #[serde(default)]
+    pub cells: Vec<Cell>,
+}
+
+fn one() -> u32 {
+    1
+}
+
+// [첫 행, 첫 열, 끝 행, 끝 열] (한 칸이면 병합하지 않고 작성)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Merge {
+    pub range: [Pos; 4],
+    #[serde(default)]
+    pub value: String,
+    #[serde(flatten)]
+    pub format: CellFormat,
+}
+
+// 값이 없으면 빈 칸 작성
+#[derive(Debug, Clone, Deserialize)]
+pub struct Cell {
+    pub at: [Pos; 2],
+    pub value: Option<Value>,
+    pub values: Option<Vec<String>>, // 오른쪽으로 이어서 작성
+    pub formula: Option<String>,
+    pub result: Option<String>, // 수식 캐시 값
+    pub rich: Option<Vec<Segment>>,
+    pub when: Option<String>, // 이 변수가 비어 있으면 값 대신 빈 칸
+    #[serde(flatten)]
+    pub format: CellFormat,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Value {
+    Number(f64),
+    Text(String),
+}
+
+// 서식이 다른 글자 조각
+#[derive(Debug, Clone, Deserialize)]
+pub struct Segment {
+    pub text: String,
+    pub style: Style,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CellFormat {
+    pub style: Option<Style>, // 없으면 빈 서식
+    pub borders: Option<BorderSpec>,
+    pub border_color: Option<String>,
+    pub bold: bool,
+}
+
+// 변별 테두리 (all 다음 각 변 적용, 지정하지 않은 변은 없음)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BorderSpec {
+    pub all: Option<Border>,
+    pub top: Option<Border>,
+    pub bottom: Option<Border>,
+    pub left: Option<Border>,
+    pub right: Option<Border>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Border {
+    None,
+    Thin,
+    Medium,
+}
+
+// 자리 표시자 값
+#[derive(Debug, Clone, Default)]
+pub struct Vars(HashMap<String, String>);
+
+impl Vars {
+    pub fn new() -> Vars {
+        Vars::default()
+    }
+    pub fn set(&mut self, key: &str, value: impl ToString) -> &mut Self {
+        self.0.insert(key.to_owned(), value.to_string());
+        self
+    }
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+    // 항목 변수로 덮어쓴 복사본
+    pub fn extend(&self, other: &Vars) -> Vars {
+        let mut vars = self.clone();
+        vars.0
+            .extend(other.0.iter().map(|(k, v)| (k.clone(), v.clone())));
+        vars
+    }
+
+    // 정수 식 (변수, 정수를 + - 로 연결)
+    pub fn eval(&self, expr: &str) -> Result<i64, Box<dyn Error>> {
+        let mut total = 0;
+        let mut sign = 1;
+        let mut term = String::new();
+        for c in expr.chars().chain(std::iter::once('+')) {
+            match c {
+                '+' | '-' => {
+                    let name = term.trim();
+                    let value = match name.parse::<i64>() {
+                        Ok(value) => value,
+                        Err(_) => self
+                            .get(name)
+                            .and_then(|value| value.parse::<i64>().ok())
+                            .ok_or_else(|| format!("알 수 없는 위치 변수: {name} ({expr})"))?,
+                    };
+                    total += sign * value;
+                    sign = if c == '+' { 1 } else { -1 };
+                    term.clear();
+                }
+                _ => term.push(c),
+            }
+        }
+        Ok(total)
+    }
+
+    // "{이름}", "{식}"을 값으로 바꿈 ("{{", "}}"는 중괄호)
+    pub fn fill(&self, text: &str) -> Result<String, Box<dyn Error>> {
+        let mut filled = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find(['{', '}']) {
+            filled.push_str(&rest[..start]);
+            let brace = &rest[start..];
+            if brace.starts_with("{{") || brace.starts_with("}}") {
+                filled.push_str(&brace[..1]);
+                rest = &brace[2..];
+                continue;
+            }
+            let end = brace
+                .find('}')
+                .filter(|_| brace.starts_with('{'))
+                .ok_or_else(|| format!("닫히지 않은 자리 표시자: {text}"))?;
+            let key = &brace[1..end];
+            match self.get(key) {
+                Some(value) => filled.push_str(value),
+                None => match self.eval(key) {
+                    Ok(value) => filled.push_str(&value.to_string()),
+                    Err(_) => return Err(format!("알 수 없는 자리 표시자: {{{key}}}").into()),
+                },
+            }
+            rest = &brace[end + 1..];
+        }
+        filled.push_str(rest);
+        Ok(filled)
+    }
+}
+
+impl Pos {
+    pub fn eval(&self, vars: &Vars) -> Result<u32, Box<dyn Error>> {
+        match self {
+            Pos::Index(index) => Ok(*index),
+            Pos::Expr(expr) => Ok(u32::try_from(vars.eval(expr)?)?),
+        }
+    }
+}
+
+// 양식 파일 경로 (config.json의 templates, 없으면 기본 양식)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TemplatePaths {
+    pub month: Option<PathBuf>,
+    pub budget: Option<PathBuf>,
+    pub account: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Templates {
+    pub month: Template,   // 월별 정산서
+    pub budget: Template,  // 예산안
+    pub account: Template, // 정산서
+}
+
+impl Templates {
+    pub fn load(paths: &TemplatePaths) -> Result<Templates, Box<dyn Error>> {
+        Ok(Templates {
+            month: Template::load(paths.month.as_deref(), MONTH)?,
+            budget: Template::load(paths.budget.as_deref(), BUDGET)?,
+            account: Template::load(paths.account.as_deref(), ACCOUNT)?,
+        })
+    }
+}
+
+impl Default for Templates {
+    fn default() -> Self {
+        Templates::load(&TemplatePaths::default()).expect("built-in templates")
+    }
+}
+
+impl Template {
+    // 이름 붙인 셀의 위치 (양식에 없으면 오류)
+    pub fn named_cell(&self, name: &str, vars: &Vars) -> Result<(u32, u16), Box<dyn Error>> {
+        let [row, col] = self
+            .names
+            .get(name)
+            .ok_or_else(|| format!("양식에 '{name}' 셀이 없습니다."))?;
+        Ok((row.eval(vars)?, col.eval(vars)? as u16))
+    }
+
+    // 파일이 지정되지 않으면 기본 양식 사용
+    pub fn load(file_path: Option<&Path>, builtin: &str) -> Result<Template, Box<dyn Error>> {
+        match file_path {
+            Some(file_path) => {
+                let reader = BufReader::new(File::open(file_path)?);
+                serde_json::from_reader(reader)
+                    .map_err(|e| format!("{}: {e}", file_path.display()).into())
+            }
+            None => Ok(serde_json::from_str(builtin)?),
+        }
+    }
+}
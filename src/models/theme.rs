@@ -0,0 +1,226 @@
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::process::Command;
+
+// 색상, 글꼴 설정 (theme.json)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub colors: Colors,
+    pub preset: FontPreset,  // 글꼴 기본값
+    pub fonts: Fonts,        // 역할별 글꼴 (비어 있으면 preset 사용)
+    pub font_size: f64,      // 월별 정산서
+    pub form_font_size: f64, // 예산안, 정산서 양식
+    pub title_font_size: f64,
+    pub print: Print,       // 인쇄 설정
+    pub detect_fonts: bool, // fc-list로 설치된 글꼴 선택 (computer마다 결과가 다름)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FontPreset {
+    Windows,  // 한글 Windows 기본 글꼴
+    Portable, // Linux, macOS에 기본 설치된 글꼴
+}
+
+// 첫 번째 글꼴 사용 (detect_fonts이면 앞에서부터 설치된 글꼴 사용)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Fonts {
+    pub title: Vec<String>,   // 제목, 머리글
+    pub body: Vec<String>,    // 본문
+    pub numbers: Vec<String>, // 금액, 비율
+}
+
+// 실제로 사용할 글꼴
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedFonts {
+    pub title: String,
+    pub body: String,
+    pub numbers: String,
+}
+
+// 인쇄 설정 (머리글, 바닥글의 {organization}은 학생회명으로 바뀜)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Print {
+    pub paper_size: u8,          // 9: A4
+    pub landscape_months: bool,  // 월별 정산서 가로 방향
+    pub repeat_rows: (u32, u32), // 월별 정산서 반복 행 (0부터 시작)
+    pub fit_to_width: bool,      // 페이지 너비에 맞춤
+    pub margins: Margins,        // inch
+    pub header: String,
+    pub footer: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Margins {
+    pub left: f64,
+    pub right: f64,
+    pub top: f64,
+    pub bottom: f64,
+    pub header: f64,
+    pub footer: f64,
+}
+
+// "#RRGGBB" 형식
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Colors {
+    pub lavender: String,
+    pub gray: String,
+    pub heading: String, // 양식 머리글
+    pub banner: String,  // 주황색 머리글
+    pub alert_font: String,
+    pub alert_fill: String,
+    pub link: String,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            colors: Colors::default(),
+            preset: FontPreset::Windows,
+            fonts: Fonts::default(),
+            font_size: 10.0,
+            form_font_size: 12.0,
+            title_font_size: 15.0,
+            print: Print::default(),
+            detect_fonts: false,
+        }
+    }
+}
+
+impl Default for Colors {
+    fn default() -> Self {
+        Colors {
+            lavender: "#CCC1DE".to_owned(),
+            gray: "#D8D8D8".to_owned(),
+            heading: "#E5E0EF".to_owned(),
+            banner: "#FCD5B6".to_owned(),
+            alert_font: "#9C0006".to_owned(),
+            alert_fill: "#FFC7CE".to_owned(),
+            link: "#0563C1".to_owned(),
+        }
+    }
+}
+
+impl Default for Print {
+    fn default() -> Self {
+        Print {
+            paper_size: 9,
+            landscape_months: true,
+            repeat_rows: (0, 5),
+            fit_to_width: true,
+            margins: Margins::default(),
+            header: "&L{organization}&R&A".to_owned(),
+            footer: "&C&P / &N".to_owned(),
+        }
+    }
+}
+
+impl Default for Margins {
+    fn default() -> Self {
+        Margins {
+            left: 0.5,
+            right: 0.5,
+            top: 0.75,
+            bottom: 0.75,
+            header: 0.3,
+            footer: 0.3,
+        }
+    }
+}
+
+impl FontPreset {
+    pub fn fonts(&self) -> Fonts {
+        let list = |fonts: &[&str]| fonts.iter().map(|font| font.to_string()).collect();
+        match self {
+            FontPreset::Windows => Fonts {
+                title: list(&["새굴림", "굴림", "Arial"]),
+                body: list(&["Batangche", "바탕체", "바탕"]),
+                numbers: list(&["Batangche", "바탕체", "Arial"]),
+            },
+            FontPreset::Portable => Fonts {
+                title: list(&[
+                    "Noto Sans CJK KR",
+                    "Apple SD Gothic Neo",
+                    "NanumGothic",
+                    "Liberation Sans",
+                ]),
+                body: list(&[
+                    "Noto Serif CJK KR",
+                    "AppleMyungjo",
+                    "NanumMyeongjo",
+                    "Noto Sans CJK KR",
+                ]),
+                numbers: list(&[
+                    "Liberation Sans",
+                    "Helvetica",
+                    "DejaVu Sans",
+                    "Noto Sans CJK KR",
+                ]),
+            },
+        }
+    }
+}
+
+impl Theme {
+    // theme 파일이 없으면 기본값 사용
+    pub fn load(file_path: &Path) -> Result<Theme, Box<dyn Error>> {
+        if !file_path.exists() {
+            return Ok(Theme::default());
+        }
+        let reader = BufReader::new(File::open(file_path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    // 역할별로 설정된 글꼴을 preset 글꼴보다 먼저 시도
+    // (없는 글꼴은 workbook을 여는 프로그램이 대체 글꼴로 표시)
+    pub fn resolve_fonts(&self) -> ResolvedFonts {
+        let installed = if self.detect_fonts {
+            installed_fonts()
+        } else {
+            None
+        };
+        let preset = self.preset.fonts();
+        let pick = |fonts: &[String], fallbacks: &[String]| {
+            let candidates = || fonts.iter().chain(fallbacks);
+            installed
+                .as_ref()
+                .and_then(|installed| candidates().find(|font| installed.contains(*font)))
+                .or_else(|| candidates().next())
+                .cloned()
+                .unwrap_or_else(|| "Arial".to_owned())
+        };
+        ResolvedFonts {
+            title: pick(&self.fonts.title, &preset.title),
+            body: pick(&self.fonts.body, &preset.body),
+            numbers: pick(&self.fonts.numbers, &preset.numbers),
+        }
+    }
+}
+
+// fontconfig로 설치된 글꼴 목록 조회 (Windows 등 fc-list가 없으면 None)
+fn installed_fonts() -> Option<HashSet<String>> {
+    let output = Command::new("fc-list")
+        .args([":", "family"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .flat_map(|line| line.split(','))
+            .map(|family| family.trim().replace('\\', ""))
+            .filter(|family| !family.is_empty())
+            .collect(),
+    )
+}
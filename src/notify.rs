@@ -0,0 +1,385 @@
+use std::env;
+use std::error::Error;
+use std::path::Path;
+
+use async_trait::async_trait;
+use lettre::{
+    message::{header::ContentType, Attachment, Mailbox, MultiPart, SinglePart},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use reqwest::Client;
+use serde_json::{json, Value};
+
+use crate::{
+    discord_message::{month_lines, month_table, payload, term_summary, truncate, won},
+    generate::Generated,
+    models::{
+        config::Config,
+        notify::{EmailConfig, NotifierConfig, Security, SlackConfig, WebhookConfig},
+    },
+    send_file::{send_with_retry, Webhook, XLSX_MIME},
+    summary::Summary,
+};
+
+// 전자우편 설정에 password가 없을 때 사용하는 환경 변수
+pub const SMTP_PASSWORD_ENV: &str = "SMTP_PASSWORD";
+
+// Slack block 제한 (글자 수)
+const SLACK_HEADER_LIMIT: usize = 150;
+const SLACK_TEXT_LIMIT: usize = 3000;
+
+// 알림 내용 (작성한 workbook과 회기 요약)
+#[derive(Debug, Clone, Copy)]
+pub struct Notice<'a> {
+    pub organization: &'a str,
+    pub period: (u16, u8),
+    pub summary: &'a Summary,
+    pub findings: usize,
+    pub file_path: Option<&'a Path>,
+}
+
+impl<'a> Notice<'a> {
+    pub fn new(organization: &'a str, generated: &'a Generated) -> Notice<'a> {
+        Notice {
+            organization,
+            period: generated.period,
+            summary: &generated.summary,
+            findings: generated.findings.len(),
+            file_path: Some(&generated.location),
+        }
+    }
+
+    pub fn title(&self) -> String {
+        format!(
+            "{} {}년도 제{}회기 재정감사",
+            self.organization, self.period.0, self.period.1
+        )
+    }
+
+    // 한눈에 볼 금액과 건수
+    pub fn figures(&self) -> Vec<(&'static str, String)> {
+        let summary = self.summary;
+        vec![
+            ("기초 잔액", won(summary.opening_balance)),
+            ("총수입", won(summary.income())),
+            ("총지출", won(summary.expense())),
+            ("기말 잔액", won(summary.balance())),
+            ("미분류 거래", format!("{}건", summary.unclassified)),
+            ("감사 의견", format!("{}건", self.findings)),
+        ]
+    }
+
+    fn file_name(&self) -> Option<String> {
+        self.file_path
+            .and_then(Path::file_name)
+            .map(|name| name.to_string_lossy().into_owned())
+    }
+
+    // 전자우편 본문
+    pub fn text(&self) -> String {
+        let mut text = format!("{}\n\n", self.title());
+        for (name, value) in self.figures() {
+            text.push_str(&format!("{name}: {value}\n"));
+        }
+        if !self.summary.months.is_empty() {
+            text.push_str("\n월별 현황\n");
+            for line in month_lines(self.summary) {
+                text.push_str(&line);
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    // 회기 요약 JSON (webhook, server mode)
+    pub fn json(&self) -> Value {
+        let summary = self.summary;
+        let months: Vec<Value> = summary
+            .months
+            .iter()
+            .map(|month| {
+                json!({
+                    "month": month.month,
+                    "carry_over": month.carry_over,
+                    "income": month.income,
+                    "expense": month.expense,
+                    "balance": month.balance(),
+                })
+            })
+            .collect();
+        json!({
+            "organization": self.organization,
+            "year": self.period.0,
+            "term": self.period.1,
+            "opening_balance": summary.opening_balance,
+            "income": summary.income(),
+            "dues": summary.dues,
+            "expense": summary.expense(),
+            "balance": summary.balance(),
+            "unclassified": summary.unclassified,
+            "findings": self.findings,
+            "months": months,
+            "workbook": self.file_name(),
+        })
+    }
+}
+
+// 알림 전송 방법
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn notify(&self, notice: &Notice<'_>) -> Result<(), Box<dyn Error>>;
+}
+
+// embed와 workbook 첨부
+pub struct DiscordNotifier {
+    webhook: Webhook,
+}
+
+impl DiscordNotifier {
+    pub fn new(webhook: Webhook) -> DiscordNotifier {
+        DiscordNotifier { webhook }
+    }
+}
+
+#[async_trait]
+impl Notifier for DiscordNotifier {
+    fn name(&self) -> &'static str {
+        "discord"
+    }
+
+    async fn notify(&self, notice: &Notice<'_>) -> Result<(), Box<dyn Error>> {
+        let embed = term_summary(
+            notice.organization,
+            notice.period,
+            notice.summary,
+            notice.findings,
+        );
+        self.webhook
+            .send(&payload(&notice.title(), &[embed]), notice.file_path)
+            .await
+    }
+}
+
+// Slack 호환 incoming webhook (Block Kit)
+pub struct SlackNotifier {
+    client: Client,
+    config: SlackConfig,
+}
+
+impl SlackNotifier {
+    pub fn new(config: SlackConfig) -> SlackNotifier {
+        SlackNotifier {
+            client: Client::new(),
+            config,
+        }
+    }
+
+    pub fn payload(notice: &Notice<'_>) -> Value {
+        let fields: Vec<Value> = notice
+            .figures()
+            .into_iter()
+            .map(|(name, value)| json!({ "type": "mrkdwn", "text": format!("*{name}*\n{value}") }))
+            .collect();
+        let mut blocks = vec![
+            json!({
+                "type": "header",
+                "text": {
+                    "type": "plain_text",
+                    "text": truncate(&notice.title(), SLACK_HEADER_LIMIT),
+                },
+            }),
+            json!({ "type": "section", "fields": fields }),
+        ];
+        if !notice.summary.months.is_empty() {
+            blocks.push(json!({
+                "type": "section",
+                "text": {
+                    "type": "mrkdwn",
+                    "text": month_table(notice.summary, SLACK_TEXT_LIMIT),
+                },
+            }));
+        }
+        if let Some(file_name) = notice.file_name() {
+            blocks.push(json!({
+                "type": "context",
+                "elements": [{ "type": "plain_text", "text": truncate(&file_name, SLACK_HEADER_LIMIT) }],
+            }));
+        }
+        json!({ "text": notice.title(), "blocks": blocks })
+    }
+}
+
+#[async_trait]
+impl Notifier for SlackNotifier {
+    fn name(&self) -> &'static str {
+        "slack"
+    }
+
+    async fn notify(&self, notice: &Notice<'_>) -> Result<(), Box<dyn Error>> {
+        let body = SlackNotifier::payload(notice);
+        send_with_retry("Slack webhook", self.config.max_retries, || {
+            Ok(self.client.post(&self.config.webhook_url).json(&body))
+        })
+        .await
+    }
+}
+
+// 회기 요약 JSON
+pub struct WebhookNotifier {
+    client: Client,
+    config: WebhookConfig,
+}
+
+impl WebhookNotifier {
+    pub fn new(config: WebhookConfig) -> WebhookNotifier {
+        WebhookNotifier {
+            client: Client::new(),
+            config,
+        }
+    }
+
+    pub fn payload(notice: &Notice<'_>) -> Value {
+        notice.json()
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn notify(&self, notice: &Notice<'_>) -> Result<(), Box<dyn Error>> {
+        let body = WebhookNotifier::payload(notice);
+        send_with_retry("Webhook", self.config.max_retries, || {
+            let mut request = self.client.post(&self.config.url).json(&body);
+            for (name, value) in &self.config.headers {
+                request = request.header(name, value);
+            }
+            Ok(request)
+        })
+        .await
+    }
+}
+
+// SMTP 전자우편 (본문에 요약, workbook 첨부)
+pub struct EmailNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+}
+
+impl EmailNotifier {
+    pub fn new(config: &EmailConfig) -> Result<EmailNotifier, Box<dyn Error>> {
+        let mut builder = match config.security {
+            Security::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host),
+            Security::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)?
+            }
+            Security::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)?,
+        }
+        .port(config.port);
+        if let Some(username) = &config.username {
+            let password = config
+                .password
+                .clone()
+                .or_else(|| env::var(SMTP_PASSWORD_ENV).ok())
+                .unwrap_or_default();
+            builder = builder.credentials(Credentials::new(username.clone(), password));
+        }
+
+        if config.to.is_empty() {
+            return Err("email notifier has no recipients".into());
+        }
+        Ok(EmailNotifier {
+            transport: builder.build(),
+            from: config.from.parse()?,
+            to: config
+                .to
+                .iter()
+                .map(|to| to.parse())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub async fn message(&self, notice: &Notice<'_>) -> Result<Message, Box<dyn Error>> {
+        let mut builder = Message::builder()
+            .from(self.from.clone())
+            .subject(notice.title());
+        for to in &self.to {
+            builder = builder.to(to.clone());
+        }
+
+        let text = SinglePart::plain(notice.text());
+        let message = match (notice.file_path, notice.file_name()) {
+            (Some(file_path), Some(file_name)) => {
+                let bytes = tokio::fs::read(file_path)
+                    .await
+                    .map_err(|e| format!("{}: {e}", file_path.display()))?;
+                builder.multipart(MultiPart::mixed().singlepart(text).singlepart(
+                    Attachment::new(file_name).body(bytes, ContentType::parse(XLSX_MIME)?),
+                ))?
+            }
+            _ => builder.singlepart(text)?,
+        };
+        Ok(message)
+    }
+}
+
+#[async_trait]
+impl Notifier for EmailNotifier {
+    fn name(&self) -> &'static str {
+        "email"
+    }
+
+    async fn notify(&self, notice: &Notice<'_>) -> Result<(), Box<dyn Error>> {
+        let message = self.message(notice).await?;
+        self.transport.send(message).await?;
+        Ok(())
+    }
+}
+
+// config의 discord (webhook이 있으면)와 notifiers
+pub fn notifiers(config: &Config) -> Result<Vec<Box<dyn Notifier>>, Box<dyn Error>> {
+    notifiers_with(config, |name| env::var(name).ok())
+}
+
+// 환경 변수 대신 lookup으로 WEBHOOK_ENV 조회
+// (WEBHOOK_ENV는 config의 discord에만 사용, notifiers의 discord는 webhook_url 필요)
+pub fn notifiers_with(
+    config: &Config,
+    lookup: impl FnOnce(&str) -> Option<String>,
+) -> Result<Vec<Box<dyn Notifier>>, Box<dyn Error>> {
+    let mut notifiers: Vec<Box<dyn Notifier>> = Vec::new();
+    if let Some(webhook) = Webhook::from_config_with(&config.discord, lookup) {
+        notifiers.push(Box::new(DiscordNotifier::new(webhook)));
+    }
+    for notifier in &config.notifiers {
+        notifiers.push(match notifier {
+            NotifierConfig::Discord(discord) => {
+                let webhook = Webhook::from_config_with(discord, |_| None)
+                    .ok_or("discord notifier has no webhook_url")?;
+                Box::new(DiscordNotifier::new(webhook))
+            }
+            NotifierConfig::Slack(slack) => Box::new(SlackNotifier::new(slack.clone())),
+            NotifierConfig::Webhook(webhook) => Box::new(WebhookNotifier::new(webhook.clone())),
+            NotifierConfig::Email(email) => Box::new(EmailNotifier::new(email)?),
+        });
+    }
+    Ok(notifiers)
+}
+
+// 모든 알림 전송 (하나가 실패해도 나머지는 계속), (이름, 결과)
+pub async fn notify_all(
+    notifiers: &[Box<dyn Notifier>],
+    notice: &Notice<'_>,
+) -> Vec<(&'static str, Result<(), String>)> {
+    let mut results = Vec::with_capacity(notifiers.len());
+    for notifier in notifiers {
+        let result = notifier.notify(notice).await.map_err(|e| e.to_string());
+        results.push((notifier.name(), result));
+    }
+    results
+}
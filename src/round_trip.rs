@@ -0,0 +1,428 @@
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::path::Path;
+
+use calamine::{open_workbook, Data as Value, DataType, Range, Reader, Xlsx};
+use regex::Regex;
+
+use crate::models::data::{BusinessType, Data, DataBuilder, Date};
+
+// 엑셀 날짜 일련번호 0 (1899.12.30)과 1970.01.01의 차이
+const EXCEL_EPOCH_DAYS: i64 = 25569;
+
+// 기존 workbook 월별 정산서의 거래 행 (row는 0부터 시작)
+#[derive(Debug, Clone)]
+pub struct SheetRow {
+    pub sheet: String,
+    pub row: u32,
+    pub date: Date,
+    pub cash_in: u32,
+    pub cash_out: u32,
+    pub balance: u32, // 은행 잔액 열이 없으면 잔고
+    pub business_type: String,
+    pub business_name: String,
+    pub remarks: String,
+    pub receipt_num: String,
+}
+
+impl SheetRow {
+    // 날짜, 수입, 지출 (같은 값이 여러 번이면 순서대로 대응)
+    fn fingerprint(&self) -> (i64, u32, u32) {
+        (self.date.days(), self.cash_in, self.cash_out)
+    }
+
+    // 수기 입력 칸이 하나라도 채워졌는지
+    pub fn is_edited(&self) -> bool {
+        [
+            &self.business_type,
+            &self.business_name,
+            &self.remarks,
+            &self.receipt_num,
+        ]
+        .iter()
+        .any(|field| !field.trim().is_empty())
+    }
+
+    // 기존 행을 거래로 변환 (시각, 거래 상대는 없음, 은행 잔액 열이 없으면 잔고)
+    pub fn to_data(&self) -> Result<Data, Box<dyn Error>> {
+        let mut builder = DataBuilder::new();
+        builder
+            .date(self.date.clone())
+            .business_type(BusinessType::from_name(&self.business_type).unwrap_or_default())
+            .cash_in(self.cash_in)
+            .cash_out(self.cash_out)
+            .balance(self.balance);
+        if !self.business_name.is_empty() {
+            builder.business_name(&self.business_name);
+        }
+        if !self.remarks.is_empty() {
+            builder.remarks(&self.remarks);
+        }
+        if !self.receipt_num.is_empty() {
+            builder.receipt_num(&self.receipt_num);
+        }
+        Ok(builder.build()?)
+    }
+}
+
+fn fingerprint(data: &Data) -> (i64, u32, u32) {
+    (data.date.days(), data.cash_in, data.cash_out)
+}
+
+// 수기 입력 유지 결과
+#[derive(Debug, Default)]
+pub struct RoundTrip {
+    pub carried: usize,          // 수기 입력을 가져온 거래 수
+    pub vanished: Vec<SheetRow>, // 새 거래내역에 없는 기존 행
+}
+
+// 기존 workbook의 월별 정산서 거래 행 읽기
+pub fn read_rows(file_path: &Path) -> Result<Vec<SheetRow>, Box<dyn Error>> {
+    let mut workbook: Xlsx<_> = open_workbook(file_path)?;
+    let month_sheet = Regex::new(r"^\d{1,2}월 정산서$")?;
+
+    let mut rows = Vec::new();
+    for sheet in workbook.sheet_names() {
+        if !month_sheet.is_match(&sheet) {
+            continue;
+        }
+        let range = workbook.worksheet_range(&sheet)?;
+        rows.extend(sheet_rows(&sheet, &range)?);
+    }
+    Ok(rows)
+}
+
+// 월별 정산서 표 머리글 행
+pub const HEADER_ROW: u32 = 5;
+
+// 머리글 이름 -> 열
+pub fn header_columns(range: &Range<Value>) -> HashMap<String, u32> {
+    (0..range.end().map_or(0, |(_, col)| col + 1))
+        .filter_map(|col| {
+            let header = range.get_value((HEADER_ROW, col))?.get_string()?;
+            Some((header.trim().to_owned(), col))
+        })
+        .collect()
+}
+
+// 6행 머리글로 열을 찾고, 날짜가 있는 행까지 읽음 (계 행에서 멈춤)
+pub fn sheet_rows(sheet: &str, range: &Range<Value>) -> Result<Vec<SheetRow>, Box<dyn Error>> {
+    let columns = header_columns(range);
+    let column = |name: &str| -> Result<u32, Box<dyn Error>> {
+        columns
+            .get(name)
+            .copied()
+            .ok_or_else(|| format!("'{sheet}'에 {name} 열이 없습니다.").into())
+    };
+    let (date_col, cash_in_col, cash_out_col) = (column("날짜")?, column("수입")?, column("지출")?);
+    let balance_col = column("은행 잔액").or_else(|_| column("잔고"))?;
+
+    let text = |row: u32, name: &str| -> String {
+        columns
+            .get(name)
+            .and_then(|&col| range.get_value((row, col)))
+            .map(cell_text)
+            .unwrap_or_default()
+    };
+    let number = |row: u32, col: u32| -> u32 {
+        range
+            .get_value((row, col))
+            .and_then(|value| value.as_f64())
+            .unwrap_or(0.0) as u32
+    };
+
+    let mut rows = Vec::new();
+    let mut row = HEADER_ROW + 1;
+    while let Some(date) = range.get_value((row, date_col)).and_then(cell_date) {
+        rows.push(SheetRow {
+            sheet: sheet.to_owned(),
+            row,
+            date,
+            cash_in: number(row, cash_in_col),
+            cash_out: number(row, cash_out_col),
+            balance: number(row, balance_col),
+            business_type: text(row, "사업구분"),
+            business_name: text(row, "사업명"),
+            remarks: text(row, "비고"),
+            receipt_num: text(row, "영수증번호"),
+        });
+        row += 1;
+    }
+    Ok(rows)
+}
+
+// 날짜 서식 셀 (일련번호로 읽히는 경우 포함)
+pub fn cell_date(value: &Value) -> Option<Date> {
+    let serial = match value {
+        Value::DateTime(datetime) => datetime.as_f64(),
+        Value::Float(serial) => *serial,
+        Value::Int(serial) => *serial as f64,
+        _ => return None,
+    };
+    Some(Date::from_days(serial.floor() as i64 - EXCEL_EPOCH_DAYS))
+}
+
+pub fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Empty => String::new(),
+        Value::Float(number) if number.fract() == 0.0 => (*number as i64).to_string(),
+        value => value.to_string(),
+    }
+}
+
+// 거래를 기존 행과 대응시켜 사업구분, 사업명, 비고, 영수증번호를 가져옴
+pub fn carry_forward(month_data_list: &mut [(u8, Vec<Data>)], rows: Vec<SheetRow>) -> RoundTrip {
+    let mut previous: HashMap<(i64, u32, u32), VecDeque<SheetRow>> = HashMap::new();
+    for row in rows {
+        previous
+            .entry(row.fingerprint())
+            .or_default()
+            .push_back(row);
+    }
+
+    let mut report = RoundTrip::default();
+    for data in month_data_list
+        .iter_mut()
+        .flat_map(|(_, data_list)| data_list.iter_mut())
+    {
+        let Some(row) = previous
+            .get_mut(&fingerprint(data))
+            .and_then(VecDeque::pop_front)
+        else {
+            continue;
+        };
+        if !row.is_edited() {
+            continue;
+        }
+
+        if let Some(business_type) = BusinessType::from_name(&row.business_type) {
+            if business_type != BusinessType::Unclassified {
+                data.business_type = business_type;
+            }
+        }
+        let keep = |field: &mut Option<String>, value: &str| {
+            if !value.trim().is_empty() {
+                *field = Some(value.to_owned());
+            }
+        };
+        keep(&mut data.business_name, &row.business_name);
+        keep(&mut data.remarks, &row.remarks);
+        keep(&mut data.receipt_num, &row.receipt_num);
+        report.carried += 1;
+    }
+
+    report.vanished = previous.into_values().flatten().collect();
+    report
+        .vanished
+        .sort_by(|a, b| (a.date.days(), &a.sheet, a.row).cmp(&(b.date.days(), &b.sheet, b.row)));
+    report
+}
+
+// 기존 행에 없는 거래만 추가해 거래내역 순서(최근 거래가 앞)로 반환
+// 같은 날짜에서는 기존 행 다음에 추가
+// 거래내역에 있는 기존 행은 시각, 거래 상대, 은행 잔액을 거래내역 값으로 (의심 거래, 잔액 불일치 점검)
+pub fn append_unseen(
+    rows: &[SheetRow],
+    statement: Vec<Data>,
+) -> Result<(Vec<Data>, usize), Box<dyn Error>> {
+    let mut seen: HashMap<(i64, u32, u32), VecDeque<usize>> = HashMap::new();
+    for (index, row) in rows.iter().enumerate() {
+        seen.entry(row.fingerprint()).or_default().push_back(index);
+    }
+
+    let mut table = rows
+        .iter()
+        .map(SheetRow::to_data)
+        .collect::<Result<Vec<_>, _>>()?;
+    let existing = table.len();
+    for data in statement.into_iter().rev() {
+        match seen
+            .get_mut(&fingerprint(&data))
+            .and_then(VecDeque::pop_front)
+        {
+            Some(index) => {
+                let row = &mut table[index];
+                row.time = data.time;
+                row.counterparty = data.counterparty;
+                row.balance = data.balance;
+            }
+            None => table.push(data),
+        }
+    }
+    let appended = table.len() - existing;
+
+    table.sort_by_key(|data| data.date.days());
+    table.reverse();
+    Ok((table, appended))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::data::Time;
+
+    // 거래내역 거래 ("yyyy.mm.dd", "hh:mm:ss")
+    fn transaction(date: &str, time: &str, cash_in: u32, cash_out: u32, balance: u32) -> Data {
+        DataBuilder::new()
+            .date(Date::new(date))
+            .time(Time::new(time))
+            .counterparty("거래 상대")
+            .cash_in(cash_in)
+            .cash_out(cash_out)
+            .balance(balance)
+            .build()
+            .unwrap()
+    }
+
+    // 기존 월별 정산서 행 (balance는 잔고 수식 값)
+    fn sheet_row(row: u32, date: &str, cash_in: u32, cash_out: u32, balance: u32) -> SheetRow {
+        SheetRow {
+            sheet: "3월 정산서".to_owned(),
+            row,
+            date: Date::new(date),
+            cash_in,
+            cash_out,
+            balance,
+            business_type: String::new(),
+            business_name: String::new(),
+            remarks: String::new(),
+            receipt_num: String::new(),
+        }
+    }
+
+    #[test]
+    fn append_unseen_keeps_statement_fields() {
+        let mut row = sheet_row(6, "2024.03.04", 0, 5000, 95000);
+        row.business_name = "간식 행사".to_owned();
+        let statement = vec![transaction("2024.03.04", "23:30:00", 0, 5000, 90000)];
+
+        let (table, appended) = append_unseen(&[row], statement).unwrap();
+        assert_eq!(appended, 0);
+        assert_eq!(table.len(), 1);
+        let data = &table[0];
+        assert_eq!(data.business_name.as_deref(), Some("간식 행사"));
+        assert_eq!(data.time.map(|time| time.hour), Some(23));
+        assert_eq!(data.counterparty.as_deref(), Some("거래 상대"));
+        // 잔고 수식 값이 아니라 은행 잔액
+        assert_eq!(data.balance, 90000);
+    }
+
+    // 같은 날짜, 금액의 거래는 순서대로 대응
+    #[test]
+    fn carry_forward_matches_duplicates_in_order() {
+        let mut first = sheet_row(6, "2024.03.04", 0, 5000, 95000);
+        first.receipt_num = "1".to_owned();
+        let mut second = sheet_row(7, "2024.03.04", 0, 5000, 90000);
+        second.receipt_num = "2".to_owned();
+        let mut month_data_list = vec![(
+            3,
+            vec![
+                transaction("2024.03.04", "10:00:00", 0, 5000, 95000),
+                transaction("2024.03.04", "11:00:00", 0, 5000, 90000),
+                transaction("2024.03.04", "12:00:00", 0, 5000, 85000),
+            ],
+        )];
+
+        let report = carry_forward(&mut month_data_list, vec![first, second]);
+        assert_eq!(report.carried, 2);
+        assert!(report.vanished.is_empty());
+        let receipts: Vec<_> = month_data_list[0]
+            .1
+            .iter()
+            .map(|data| data.receipt_num.as_deref())
+            .collect();
+        assert_eq!(receipts, [Some("1"), Some("2"), None]);
+    }
+
+    // 수기 입력만 가져오고 빈 칸, 미정은 거래내역 값 유지
+    #[test]
+    fn carry_forward_keeps_edited_fields() {
+        let mut row = sheet_row(6, "2024.03.04", 0, 5000, 95000);
+        row.business_type = "일반사업".to_owned();
+        row.business_name = "간식 행사".to_owned();
+        let mut unclassified = sheet_row(7, "2024.03.05", 0, 3000, 92000);
+        unclassified.business_type = BusinessType::Unclassified.label().to_owned();
+        let mut data = transaction("2024.03.04", "10:00:00", 0, 5000, 95000);
+        data.remarks = Some("거래내역 비고".to_owned());
+        let mut other = transaction("2024.03.05", "10:00:00", 0, 3000, 92000);
+        other.business_type = BusinessType::OngoingBusiness;
+        let mut month_data_list = vec![(3, vec![data, other])];
+
+        let report = carry_forward(&mut month_data_list, vec![row, unclassified]);
+        assert_eq!(report.carried, 2);
+        let [data, other] = &month_data_list[0].1[..] else {
+            panic!("거래 2건");
+        };
+        assert_eq!(data.business_type, BusinessType::GeneralBusiness);
+        assert_eq!(data.business_name.as_deref(), Some("간식 행사"));
+        assert_eq!(data.remarks.as_deref(), Some("거래내역 비고"));
+        assert_eq!(data.time.map(|time| time.hour), Some(10));
+        assert_eq!(other.business_type, BusinessType::OngoingBusiness);
+    }
+
+    // 새 거래내역에 없는 기존 행은 날짜순으로 보고
+    #[test]
+    fn carry_forward_reports_vanished_rows() {
+        let mut vanished = sheet_row(7, "2024.03.05", 0, 3000, 92000);
+        vanished.receipt_num = "2".to_owned();
+        let rows = vec![
+            sheet_row(8, "2024.03.09", 10000, 0, 102000),
+            sheet_row(6, "2024.03.04", 0, 5000, 95000),
+            vanished,
+        ];
+        let mut month_data_list = vec![(
+            3,
+            vec![transaction("2024.03.04", "10:00:00", 0, 5000, 95000)],
+        )];
+
+        let report = carry_forward(&mut month_data_list, rows);
+        // 수기 입력이 없는 행은 대응돼도 carried가 아님
+        assert_eq!(report.carried, 0);
+        let vanished: Vec<_> = report.vanished.iter().map(|row| row.row).collect();
+        assert_eq!(vanished, [7, 8]);
+    }
+
+    // 같은 거래가 기존 행보다 많으면 남는 만큼만 추가
+    #[test]
+    fn append_unseen_appends_extra_duplicates() {
+        let rows = [
+            sheet_row(6, "2024.03.04", 0, 5000, 95000),
+            sheet_row(7, "2024.03.04", 0, 5000, 90000),
+        ];
+        let statement = vec![
+            transaction("2024.03.04", "12:00:00", 0, 5000, 85000),
+            transaction("2024.03.04", "11:00:00", 0, 5000, 90000),
+            transaction("2024.03.04", "10:00:00", 0, 5000, 95000),
+        ];
+
+        let (table, appended) = append_unseen(&rows, statement).unwrap();
+        assert_eq!(appended, 1);
+        // 최근 거래가 앞, 같은 날짜에서는 추가한 거래가 기존 행 다음
+        let balances: Vec<_> = table.iter().map(|data| data.balance).collect();
+        assert_eq!(balances, [85000, 90000, 95000]);
+    }
+
+    // 수기로 고친 행과 거래내역에 없는 행은 그대로 유지
+    #[test]
+    fn append_unseen_keeps_edited_and_vanished_rows() {
+        let mut edited = sheet_row(6, "2024.03.04", 0, 5000, 95000);
+        edited.business_type = "공약사업".to_owned();
+        edited.receipt_num = "1".to_owned();
+        let vanished = sheet_row(7, "2024.03.05", 0, 3000, 92000);
+        let statement = vec![
+            transaction("2024.03.09", "10:00:00", 10000, 0, 102000),
+            transaction("2024.03.04", "10:00:00", 0, 5000, 95000),
+        ];
+
+        let (table, appended) = append_unseen(&[edited, vanished], statement).unwrap();
+        assert_eq!(appended, 1);
+        let [new, vanished, edited] = &table[..] else {
+            panic!("거래 3건");
+        };
+        assert_eq!(new.cash_in, 10000);
+        assert_eq!(vanished.cash_out, 3000);
+        assert!(vanished.time.is_none());
+        assert_eq!(edited.business_type, BusinessType::PledgedBusiness);
+        assert_eq!(edited.receipt_num.as_deref(), Some("1"));
+    }
+}
@@ -0,0 +1,184 @@
+use std::env;
+use std::error::Error;
+use std::path::Path;
+use std::time::Duration;
+
+use reqwest::{
+    header::RETRY_AFTER,
+    multipart::{Form, Part},
+    Client, RequestBuilder, Response, StatusCode,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::models::discord::DiscordConfig;
+
+// config에 webhook_url이 없을 때 사용하는 환경 변수
+pub const WEBHOOK_ENV: &str = "DISCORD_WEBHOOK_URL";
+
+pub const XLSX_MIME: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+
+// Retry-After가 없을 때 첫 재시도 대기 (재시도마다 두 배)
+const BACKOFF: Duration = Duration::from_millis(500);
+
+// 429 응답의 retry_after 상한 (이보다 길면 이 시간만 기다린 뒤 재시도)
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+// 429 응답 본문
+#[derive(Deserialize)]
+struct RateLimited {
+    retry_after: f64, // 초
+}
+
+// Discord webhook
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    client: Client,
+    url: String,
+    max_retries: u32,
+}
+
+impl Webhook {
+    pub fn new(url: impl Into<String>, max_retries: u32) -> Webhook {
+        Webhook {
+            client: Client::new(),
+            url: url.into(),
+            max_retries,
+        }
+    }
+
+    // config의 webhook_url, 없으면 DISCORD_WEBHOOK_URL (둘 다 없으면 None)
+    pub fn from_config(config: &DiscordConfig) -> Option<Webhook> {
+        Webhook::from_config_with(config, |name| env::var(name).ok())
+    }
+
+    // 환경 변수 대신 lookup으로 WEBHOOK_ENV 조회
+    pub fn from_config_with(
+        config: &DiscordConfig,
+        lookup: impl FnOnce(&str) -> Option<String>,
+    ) -> Option<Webhook> {
+        let url = config
+            .webhook_url
+            .clone()
+            .or_else(|| lookup(WEBHOOK_ENV))
+            .filter(|url| !url.trim().is_empty())?;
+        Some(Webhook::new(url, config.max_retries))
+    }
+
+    // 메시지와 함께 파일 첨부
+    pub async fn send_file(&self, file_path: &Path, content: &str) -> Result<(), Box<dyn Error>> {
+        self.send(&json!({ "content": content }), Some(file_path))
+            .await
+    }
+
+    // payload_json (content, embeds 등)과 파일 전송
+    pub async fn send(
+        &self,
+        payload: &Value,
+        file_path: Option<&Path>,
+    ) -> Result<(), Box<dyn Error>> {
+        let file = match file_path {
+            Some(file_path) => Some((
+                file_path
+                    .file_name()
+                    .map_or_else(|| "workbook.xlsx".into(), |name| name.to_string_lossy())
+                    .into_owned(),
+                tokio::fs::read(file_path)
+                    .await
+                    .map_err(|e| format!("{}: {e}", file_path.display()))?,
+            )),
+            None => None,
+        };
+        let payload = payload.to_string();
+
+        let file = file.as_ref();
+        send_with_retry("Discord webhook", self.max_retries, || {
+            let mut form = Form::new().text("payload_json", payload.clone());
+            if let Some((file_name, bytes)) = file {
+                form = form.part(
+                    "files[0]",
+                    Part::bytes(bytes.clone())
+                        .file_name(file_name.clone())
+                        .mime_str(XLSX_MIME)?,
+                );
+            }
+            Ok(self.client.post(&self.url).multipart(form))
+        })
+        .await
+    }
+}
+
+// 429 응답은 retry_after 만큼, 5xx 응답과 연결 오류는 점점 길게 기다린 뒤 재시도
+// multipart form 등은 재사용할 수 없어 시도마다 request로 새로 만듦
+pub async fn send_with_retry(
+    service: &str,
+    max_retries: u32,
+    request: impl Fn() -> Result<RequestBuilder, Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    let mut attempt = 0;
+    loop {
+        let request = request()?;
+        let (wait, error) = match request.send().await {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS => (
+                retry_after(response).await,
+                "429 Too Many Requests".to_owned(),
+            ),
+            Ok(response) if response.status().is_server_error() => {
+                (backoff(attempt), response.status().to_string())
+            }
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(format!("{service} failed: {status} {body}").into());
+            }
+            Err(e) if e.is_connect() || e.is_timeout() => (backoff(attempt), e.to_string()),
+            Err(e) => return Err(e.into()),
+        };
+
+        if attempt >= max_retries {
+            return Err(format!("{service} failed after {} attempts: {error}", attempt + 1).into());
+        }
+        attempt += 1;
+        tokio::time::sleep(wait).await;
+    }
+}
+
+// 본문의 retry_after, 없으면 Retry-After 머리글 (초, 최대 MAX_RETRY_AFTER)
+async fn retry_after(response: Response) -> Duration {
+    let header = response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<f64>().ok());
+    let body = response
+        .json::<RateLimited>()
+        .await
+        .ok()
+        .map(|body| body.retry_after);
+    body.or(header)
+        .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+        .map_or(BACKOFF, |seconds| {
+            Duration::try_from_secs_f64(seconds)
+                .map_or(MAX_RETRY_AFTER, |wait| wait.min(MAX_RETRY_AFTER))
+        })
+}
+
+fn backoff(attempt: u32) -> Duration {
+    BACKOFF.saturating_mul(2u32.saturating_pow(attempt))
+}
+
+// 작성한 workbook을 Discord로 전송 (webhook이 설정되지 않으면 false)
+pub async fn send_discord_xlsx(
+    config: &DiscordConfig,
+    file_path: &Path,
+    content: &str,
+) -> Result<bool, Box<dyn Error>> {
+    match Webhook::from_config(config) {
+        Some(webhook) => {
+            webhook.send_file(file_path, content).await?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
@@ -0,0 +1,229 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use axum::{
+    extract::{DefaultBodyLimit, Multipart, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use serde_json::{json, Value};
+use tokio::{net::TcpListener, runtime::Handle, task};
+
+use crate::{
+    generate::{generate_to_buffer, summarize},
+    merge_tables,
+    models::{
+        budget::BudgetPlan,
+        config::Config,
+        data::{Data, VariantName},
+    },
+    notify::Notice,
+    read_tables,
+    send_file::XLSX_MIME,
+};
+
+// 요청 크기 제한 (거래내역 여러 개)
+pub const BODY_LIMIT: usize = 16 * 1024 * 1024;
+
+// 업로드한 파일 (multipart field: statement 여러 개, profile, budget)
+#[derive(Debug, Default)]
+struct Upload {
+    statements: Vec<Vec<u8>>,
+    profile: Option<Vec<u8>>, // config.json 형식
+    budget: Option<Vec<u8>>,  // 예산안 입력 파일 형식
+}
+
+// 요청 오류 ({"error": "..."})
+#[derive(Debug)]
+struct ServerError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ServerError {
+    fn bad_request(message: impl ToString) -> ServerError {
+        ServerError {
+            status: StatusCode::BAD_REQUEST,
+            message: message.to_string(),
+        }
+    }
+
+    fn unprocessable(message: impl ToString) -> ServerError {
+        ServerError {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            message: message.to_string(),
+        }
+    }
+
+    fn internal(message: impl ToString) -> ServerError {
+        ServerError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl IntoResponse for ServerError {
+    fn into_response(self) -> Response {
+        (self.status, Json(json!({ "error": self.message }))).into_response()
+    }
+}
+
+// POST /workbook: xlsx, POST /summary: 회기 요약 JSON
+pub fn router(config: Config) -> Router {
+    Router::new()
+        .route("/workbook", post(workbook))
+        .route("/summary", post(summary))
+        .layer(DefaultBodyLimit::max(BODY_LIMIT))
+        .with_state(Arc::new(config))
+}
+
+pub async fn serve(listener: TcpListener, config: Config) -> Result<(), Box<dyn Error>> {
+    axum::serve(listener, router(config)).await?;
+    Ok(())
+}
+
+async fn workbook(
+    State(config): State<Arc<Config>>,
+    multipart: Multipart,
+) -> Result<Response, ServerError> {
+    let (config, plan, table) = parse_upload(&config, read_upload(multipart).await?)?;
+
+    // xlsx 작성과 압축은 오래 걸리므로 blocking thread에서
+    let runtime = Handle::current();
+    let (generated, buffer) = task::spawn_blocking(move || {
+        runtime
+            .block_on(generate_to_buffer(&config, &plan, table))
+            .map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(ServerError::internal)?
+    .map_err(ServerError::unprocessable)?;
+    let file_name = generated.location.to_string_lossy();
+    Ok((
+        [
+            (header::CONTENT_TYPE, XLSX_MIME.to_owned()),
+            (header::CONTENT_DISPOSITION, content_disposition(&file_name)),
+        ],
+        buffer,
+    )
+        .into_response())
+}
+
+async fn summary(
+    State(config): State<Arc<Config>>,
+    multipart: Multipart,
+) -> Result<Json<Value>, ServerError> {
+    // workbook은 작성하지 않고 계산만
+    let (config, plan, table) = parse_upload(&config, read_upload(multipart).await?)?;
+    let generated = summarize(&config, &plan, table).map_err(ServerError::unprocessable)?;
+    let mut summary = Notice::new(&config.organization, &generated).json();
+    summary["opinions"] = generated
+        .findings
+        .iter()
+        .map(|finding| {
+            json!({
+                "severity": finding.severity.variant_name(),
+                "check": finding.check.variant_name(),
+                "sheet": finding.sheet,
+                "message": finding.message,
+            })
+        })
+        .collect();
+    Ok(Json(summary))
+}
+
+async fn read_upload(mut multipart: Multipart) -> Result<Upload, ServerError> {
+    let mut upload = Upload::default();
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(ServerError::bad_request)?
+    {
+        let name = field.name().unwrap_or_default().to_owned();
+        let bytes = field
+            .bytes()
+            .await
+            .map_err(|e| ServerError::bad_request(format!("{name}: {e}")))?
+            .to_vec();
+        match name.as_str() {
+            "statement" => upload.statements.push(bytes),
+            "profile" => upload.profile = Some(bytes),
+            "budget" => upload.budget = Some(bytes),
+            _ => return Err(ServerError::bad_request(format!("unknown field {name}"))),
+        }
+    }
+    Ok(upload)
+}
+
+// 업로드한 파일 해석, (설정, 예산안, 거래)
+fn parse_upload(
+    config: &Config,
+    upload: Upload,
+) -> Result<(Config, BudgetPlan, Vec<Data>), ServerError> {
+    let config = profile(config, upload.profile.as_deref())?;
+    let plan = match &upload.budget {
+        Some(budget) => {
+            let plan = serde_json::from_slice::<BudgetPlan>(budget)
+                .map_err(|e| ServerError::bad_request(format!("budget: {e}")))?;
+            plan.validate()
+                .map_err(|e| ServerError::bad_request(format!("budget: {e}")))?;
+            plan
+        }
+        None => BudgetPlan::default(),
+    };
+
+    if upload.statements.is_empty() {
+        return Err(ServerError::bad_request("statement is required"));
+    }
+    let tables = upload
+        .statements
+        .iter()
+        .map(|statement| read_tables(statement.as_slice()).map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| ServerError::bad_request(format!("statement: {e}")))?;
+    let table = merge_tables(tables);
+    if table.is_empty() {
+        return Err(ServerError::unprocessable("no transactions in statements"));
+    }
+    Ok((config, plan, table))
+}
+
+// 업로드한 설정은 workbook 내용에 관한 항목만 사용
+// (server의 파일 경로와 알림 설정은 바꾸지 않음)
+fn profile(config: &Config, profile: Option<&[u8]>) -> Result<Config, ServerError> {
+    let mut config = config.clone();
+    if let Some(profile) = profile {
+        let profile: Config = serde_json::from_slice(profile)
+            .map_err(|e| ServerError::bad_request(format!("profile: {e}")))?;
+        config.organization = profile.organization;
+        config.ledger = profile.ledger;
+        config.bank_balance = profile.bank_balance;
+        config.password = profile.password;
+        config.audit = profile.audit;
+    }
+    Ok(config)
+}
+
+// 한글 파일 이름 (RFC 6266, ASCII가 아닌 글자는 filename*로)
+fn content_disposition(file_name: &str) -> String {
+    let fallback: String = file_name
+        .chars()
+        .map(|c| match c {
+            ' '..='~' if c != '"' && c != '\\' => c,
+            _ => '_',
+        })
+        .collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect();
+    format!("attachment; filename=\"{fallback}\"; filename*=UTF-8''{encoded}")
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use crate::{
+    models::{
+        budget::BudgetPlan,
+        data::{BusinessType, Data, VariantName},
+    },
+    DUES_NAME,
+};
+
+// 월별 정산서 계산 결과
+#[derive(Debug, Clone, Default)]
+pub struct MonthSummary {
+    pub month: u8,
+    pub carry_over: i64,    // 이월금 (전월 잔액)
+    pub income: i64,        // 수입 계
+    pub expense: i64,       // 지출 계
+    pub balances: Vec<i64>, // 거래별 잔고
+    pub mismatches: usize,  // 잔고와 은행 잔액이 다른 거래 수
+}
+
+impl MonthSummary {
+    // 월말 잔액
+    pub fn balance(&self) -> i64 {
+        self.carry_over + self.income - self.expense
+    }
+}
+
+// 수식의 캐시 값 (수식을 다시 계산하지 않는 뷰어에서 표시됨)
+#[derive(Debug, Clone, Default)]
+pub struct Summary {
+    pub opening_balance: i64, // 기초 잔액 (예산안 이월금)
+    pub months: Vec<MonthSummary>,
+    pub dues: i64,                               // 학생회비 납부 수입
+    pub unclassified: usize,                     // 사업구분 미정 거래 수
+    spent: HashMap<(&'static str, String), i64>, // 사업구분, 사업명별 지출
+}
+
+impl Summary {
+    pub fn new(month_data_list: &[(u8, Vec<Data>)], plan: &BudgetPlan) -> Summary {
+        let opening_balance = plan.carry_over.unwrap_or(0) as i64;
+        let mut summary = Summary {
+            opening_balance,
+            ..Summary::default()
+        };
+
+        let mut carry_over = opening_balance;
+        for (month, data_list) in month_data_list {
+            let mut month_summary = MonthSummary {
+                month: *month,
+                carry_over,
+                ..MonthSummary::default()
+            };
+            let mut balance = carry_over;
+            for data in data_list {
+                let (cash_in, cash_out) = (data.cash_in as i64, data.cash_out as i64);
+                balance += cash_in - cash_out;
+                month_summary.income += cash_in;
+                month_summary.expense += cash_out;
+                month_summary.balances.push(balance);
+                if balance != data.balance as i64 {
+                    month_summary.mismatches += 1;
+                }
+
+                if data.business_type == BusinessType::Unclassified {
+                    summary.unclassified += 1;
+                }
+
+                let business_name = data.business_name.clone().unwrap_or_default();
+                if business_name == DUES_NAME {
+                    summary.dues += cash_in;
+                }
+                *summary
+                    .spent
+                    .entry((data.business_type.variant_name(), business_name))
+                    .or_default() += cash_out;
+            }
+            carry_over = month_summary.balance();
+            summary.months.push(month_summary);
+        }
+        summary
+    }
+
+    pub fn month(&self, month: u8) -> Option<&MonthSummary> {
+        self.months.iter().find(|summary| summary.month == month)
+    }
+
+    pub fn income(&self) -> i64 {
+        self.months.iter().map(|summary| summary.income).sum()
+    }
+
+    pub fn expense(&self) -> i64 {
+        self.months.iter().map(|summary| summary.expense).sum()
+    }
+
+    pub fn mismatches(&self) -> usize {
+        self.months.iter().map(|summary| summary.mismatches).sum()
+    }
+
+    // 마지막 달 잔액
+    pub fn balance(&self) -> i64 {
+        self.months
+            .last()
+            .map_or(self.opening_balance, |summary| summary.balance())
+    }
+
+    // 예산안 사업의 집행액 (사업구분, 사업명 모두 일치)
+    pub fn spent(&self, business_type: &BusinessType, business_name: &str) -> i64 {
+        self.spent
+            .get(&(business_type.variant_name(), business_name.to_owned()))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    // 사업구분별 지출
+    pub fn spent_by_type(&self, business_type: &str) -> i64 {
+        self.spent
+            .iter()
+            .filter(|((t, _), _)| *t == business_type)
+            .map(|(_, spent)| spent)
+            .sum()
+    }
+}
+
+// 집행률 (예산이 0이면 0)
+pub fn rate(amount: i64, budget: i64) -> f64 {
+    match budget {
+        0 => 0.0,
+        _ => amount as f64 / budget as f64,
+    }
+}
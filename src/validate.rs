@@ -0,0 +1,506 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+use calamine::{open_workbook, Data as Value, Range, Reader, Xlsx};
+use regex::Regex;
+
+use crate::{
+    cell_name,
+    models::data::BusinessType,
+    month_name,
+    round_trip::{cell_text, header_columns, sheet_rows, SheetRow, HEADER_ROW},
+    term_months, OPENING_BALANCE_NAME,
+};
+
+// 검증 항목 결과 (문제가 없으면 통과)
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub issues: Vec<String>,
+}
+
+impl CheckResult {
+    fn new(name: &'static str) -> CheckResult {
+        CheckResult {
+            name,
+            issues: Vec::new(),
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+// 제출된 workbook 검증 보고서
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub file: String,
+    pub checks: Vec<CheckResult>,
+}
+
+impl Report {
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(CheckResult::passed)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "검증: {}", self.file)?;
+        for check in &self.checks {
+            match check.passed() {
+                true => writeln!(f, "[PASS] {}", check.name)?,
+                false => writeln!(f, "[FAIL] {} ({}건)", check.name, check.issues.len())?,
+            }
+            for issue in &check.issues {
+                writeln!(f, "    - {issue}")?;
+            }
+        }
+        let passed = self.checks.iter().filter(|check| check.passed()).count();
+        writeln!(
+            f,
+            "결과: {} ({passed}/{} 통과)",
+            if self.passed() { "PASS" } else { "FAIL" },
+            self.checks.len()
+        )
+    }
+}
+
+// 검증 범위
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scope {
+    Structure, // 필수 sheet, 합계 및 이월금, 사업구분 (작성 직후에도 통과)
+    All,       // 수기 입력이 끝났는지 (영수증 번호) 포함
+}
+
+// 월별 정산서 값
+struct Month {
+    month: u8,
+    sheet: String,
+    range: Range<Value>,
+    rows: Vec<SheetRow>,
+}
+
+impl Month {
+    // 계 행 (마지막 거래 다음의 입력 행 다음)
+    fn total_row(&self) -> u32 {
+        HEADER_ROW + 2 + self.rows.len() as u32
+    }
+}
+
+// 정의된 이름이 가리키는 한 칸 (sheet, 행, 열), 범위는 제외
+fn named_cells(names: &[(String, String)]) -> HashMap<String, (String, u32, u32)> {
+    let pattern = Regex::new(r"^=?'?(.+?)'?!\$([A-Z]+)\$(\d+)$").unwrap();
+    names
+        .iter()
+        .filter_map(|(name, reference)| {
+            let caps = pattern.captures(reference)?;
+            let col = caps[2]
+                .bytes()
+                .fold(0, |col, letter| col * 26 + (letter - b'A' + 1) as u32);
+            let row = caps[3].parse::<u32>().ok()?;
+            Some((name.clone(), (caps[1].to_owned(), row - 1, col - 1)))
+        })
+        .collect()
+}
+
+fn number(range: &Range<Value>, row: u32, col: u32) -> i64 {
+    range
+        .get_value((row, col))
+        .and_then(|value| match value {
+            Value::Float(number) => Some(number.round() as i64),
+            Value::Int(number) => Some(*number),
+            Value::String(text) => text.trim().replace(',', "").parse().ok(),
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+// 필수 sheet, 합계와 이월금, 사업구분 검사 (Scope::All이면 영수증 번호도)
+pub fn validate(file_path: &Path, scope: Scope) -> Result<Report, Box<dyn Error>> {
+    let mut workbook: Xlsx<_> = open_workbook(file_path)?;
+    let sheet_names = workbook.sheet_names();
+
+    // 필수 sheet
+    let mut sheets = CheckResult::new("필수 sheet");
+    let budget_pattern = Regex::new(r"^(\d{4})년도 제(\d)회기 예산안$")?;
+    let period = sheet_names.iter().find_map(|name| {
+        let caps = budget_pattern.captures(name)?;
+        Some((caps[1].parse::<u16>().ok()?, caps[2].parse::<u8>().ok()?))
+    });
+    let Some(period) = period else {
+        sheets
+            .issues
+            .push("'{연도}년도 제{회기}회기 예산안' sheet가 없습니다.".to_owned());
+        return Ok(Report {
+            file: file_path.display().to_string(),
+            checks: vec![sheets],
+        });
+    };
+    let budget_sheet = format!("{}년도 제{}회기 예산안", period.0, period.1);
+    let account_sheet = format!("{}년도 제{}회기 정산서", period.0, period.1);
+    let mut expected = vec![account_sheet.clone()];
+    expected.extend(term_months(period.1).map(|month| format!("{month}월 정산서")));
+    for name in &expected {
+        if !sheet_names.contains(name) {
+            sheets.issues.push(format!("'{name}' sheet가 없습니다."));
+        }
+    }
+
+    let mut months = Vec::new();
+    for month in term_months(period.1) {
+        let sheet = format!("{month}월 정산서");
+        if !sheet_names.contains(&sheet) {
+            continue;
+        }
+        let range = workbook.worksheet_range(&sheet)?;
+        match sheet_rows(&sheet, &range) {
+            Ok(rows) => months.push(Month {
+                month,
+                sheet,
+                range,
+                rows,
+            }),
+            Err(e) => sheets.issues.push(e.to_string()),
+        }
+    }
+
+    // 합계 및 이월금 연결 (앞 셀에 기록된 값 기준, 한 곳의 오류가 뒤로 번지지 않음)
+    // 셀 위치는 workbook의 정의된 이름 (양식을 바꿔도 같은 셀), 없으면 기본 양식 위치
+    let mut sums = CheckResult::new("합계 및 이월금");
+    let names = named_cells(workbook.defined_names());
+    let (opening_sheet, row, col) =
+        names
+            .get(OPENING_BALANCE_NAME)
+            .cloned()
+            .unwrap_or((budget_sheet, 6, 1));
+    let opening_balance = match workbook.worksheet_range(&opening_sheet) {
+        Ok(range) => number(&range, row, col),
+        Err(_) => 0,
+    };
+    let mut recorded = HashMap::new(); // 월별 (수입, 지출, 이월금, 잔액)
+    let mut carry_over = opening_balance;
+    for month in &months {
+        let (range, sheet) = (&month.range, &month.sheet);
+        let columns = header_columns(range);
+        let balance_col = columns.get("잔고").copied().unwrap_or(5);
+        let cell = |label: &str, default: (u32, u32)| {
+            names
+                .get(&month_name(label, month.month))
+                .filter(|(name_sheet, _, _)| name_sheet == sheet)
+                .map_or(default, |&(_, row, col)| (row, col))
+        };
+        let mut check = |(row, col): (u32, u32), label: &str, expected: i64| -> i64 {
+            let actual = number(range, row, col);
+            if actual != expected {
+                sums.issues.push(format!(
+                    "'{sheet}' {} {label} {actual}원이 계산 값 {expected}원과 다릅니다.",
+                    cell_name(row, col)
+                ));
+            }
+            actual
+        };
+
+        let income = check(
+            cell("수입", (1, 3)),
+            "수입",
+            month.rows.iter().map(|row| row.cash_in as i64).sum(),
+        );
+        let expense = check(
+            cell("지출", (2, 3)),
+            "지출",
+            month.rows.iter().map(|row| row.cash_out as i64).sum(),
+        );
+        let month_carry_over = check(cell("이월금", (3, 3)), "이월금", carry_over);
+
+        let mut balance = month_carry_over;
+        for row in &month.rows {
+            let expected = balance + row.cash_in as i64 - row.cash_out as i64;
+            balance = check((row.row, balance_col), "잔고", expected);
+        }
+        let total = cell("잔액", (month.total_row(), balance_col));
+        let balance = check(total, "잔액", balance);
+        recorded.insert(month.month, (income, expense, month_carry_over, balance));
+        carry_over = balance;
+    }
+
+    // 정산서 sheet의 월별 행
+    if let Ok(range) = workbook.worksheet_range(&account_sheet) {
+        for row in 4..4 + term_months(period.1).count() as u32 {
+            let label = range.get_value((row, 1)).map(cell_text).unwrap_or_default();
+            let Some(&(income, expense, carry_over, balance)) = label
+                .trim_end_matches('월')
+                .parse::<u8>()
+                .ok()
+                .and_then(|month| recorded.get(&month))
+            else {
+                continue;
+            };
+            for (col, label, expected) in [
+                (2, "수입", income),
+                (3, "지출", expense),
+                (4, "이월금", carry_over),
+                (5, "총잔액", balance),
+            ] {
+                let actual = number(&range, row, col);
+                if actual != expected {
+                    sums.issues.push(format!(
+                        "'{account_sheet}' {} {label} {actual}원이 월별 정산서 값 {expected}원과 다릅니다.",
+                        cell_name(row, col)
+                    ));
+                }
+            }
+        }
+    }
+
+    // 사업구분
+    let mut business_types = CheckResult::new("사업구분");
+    for month in &months {
+        for row in &month.rows {
+            if BusinessType::from_name(&row.business_type).is_none() {
+                business_types.issues.push(format!(
+                    "'{}' {} 사업구분 '{}'은(는) 올바른 값이 아닙니다.",
+                    month.sheet,
+                    cell_name(row.row, 1),
+                    row.business_type
+                ));
+            }
+        }
+    }
+
+    let mut checks = vec![sheets, sums, business_types];
+    if scope == Scope::Structure {
+        return Ok(Report {
+            file: file_path.display().to_string(),
+            checks,
+        });
+    }
+
+    // 영수증 번호 (지출은 필수, 중복 불가)
+    let mut receipts = CheckResult::new("영수증 번호");
+    let mut seen: HashMap<&str, String> = HashMap::new();
+    for month in &months {
+        for row in &month.rows {
+            let location = format!("'{}' {}행", month.sheet, row.row + 1);
+            let receipt_num = row.receipt_num.trim();
+            if receipt_num.is_empty() {
+                if row.cash_out > 0 {
+                    receipts.issues.push(format!(
+                        "{location} {} 지출 {}원의 영수증 번호가 없습니다.",
+                        row.date, row.cash_out
+                    ));
+                }
+                continue;
+            }
+            match seen.get(receipt_num) {
+                Some(first) => receipts.issues.push(format!(
+                    "{location} 영수증 번호 '{receipt_num}'이(가) 중복됩니다. (처음: {first})"
+                )),
+                None => {
+                    seen.insert(receipt_num, location);
+                }
+            }
+        }
+    }
+
+    checks.push(receipts);
+    Ok(Report {
+        file: file_path.display().to_string(),
+        checks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::path::PathBuf;
+
+    use rust_xlsxwriter::Workbook;
+
+    use super::*;
+    use crate::models::data::Date;
+
+    // 1회기 최소 workbook (기초잔액 100,000원, 3월 거래만, 기본 양식 위치)
+    struct Fixture {
+        rows: Vec<(u32, u32, &'static str, &'static str)>, // 3월 (수입, 지출, 사업구분, 영수증번호)
+        missing: Option<&'static str>,                     // 작성하지 않을 sheet
+        income_error: i64,                                 // 3월 수입 칸에 더할 오차
+    }
+
+    impl Fixture {
+        fn new(rows: Vec<(u32, u32, &'static str, &'static str)>) -> Fixture {
+            Fixture {
+                rows,
+                missing: None,
+                income_error: 0,
+            }
+        }
+
+        fn write(&self, name: &str) -> PathBuf {
+            let mut workbook = Workbook::new();
+            let budget_sheet = "2024년도 제1회기 예산안";
+            let mut sheets = vec![
+                budget_sheet.to_owned(),
+                "2024년도 제1회기 정산서".to_owned(),
+            ];
+            sheets.extend(term_months(1).map(|month| format!("{month}월 정산서")));
+
+            let mut balance = 100_000i64;
+            for sheet in sheets {
+                if self.missing == Some(sheet.as_str()) {
+                    continue;
+                }
+                let worksheet = workbook.add_worksheet();
+                worksheet.set_name(&sheet).unwrap();
+                if sheet == budget_sheet {
+                    worksheet.write(6, 1, balance as f64).unwrap();
+                    continue;
+                }
+                let Some(month) = sheet.strip_suffix("월 정산서") else {
+                    continue;
+                };
+
+                let headers = [
+                    "날짜",
+                    "사업구분",
+                    "사업명",
+                    "수입",
+                    "지출",
+                    "잔고",
+                    "비고",
+                    "영수증번호",
+                ];
+                worksheet.write_row(HEADER_ROW, 0, headers).unwrap();
+                let (rows, income_error): (&[_], i64) = match month {
+                    "3" => (&self.rows, self.income_error),
+                    _ => (&[], 0),
+                };
+                let (mut income, mut expense) = (0i64, 0i64);
+                worksheet.write(3, 3, balance as f64).unwrap();
+                for (row, &(cash_in, cash_out, business_type, receipt_num)) in
+                    (HEADER_ROW + 1..).zip(rows)
+                {
+                    let date = Date::new(&format!("2024.03.{row:02}"));
+                    income += cash_in as i64;
+                    expense += cash_out as i64;
+                    balance += cash_in as i64 - cash_out as i64;
+                    worksheet
+                        .write(row, 0, (date.days() + EXCEL_EPOCH_DAYS) as f64)
+                        .unwrap()
+                        .write(row, 1, business_type)
+                        .unwrap()
+                        .write(row, 3, cash_in)
+                        .unwrap()
+                        .write(row, 4, cash_out)
+                        .unwrap()
+                        .write(row, 5, balance as f64)
+                        .unwrap()
+                        .write(row, 7, receipt_num)
+                        .unwrap();
+                }
+                worksheet
+                    .write(1, 3, (income + income_error) as f64)
+                    .unwrap()
+                    .write(2, 3, expense as f64)
+                    .unwrap()
+                    .write(HEADER_ROW + 2 + rows.len() as u32, 5, balance as f64)
+                    .unwrap();
+            }
+
+            let file_path =
+                env::temp_dir().join(format!("validate_{}_{name}.xlsx", std::process::id()));
+            workbook.save(&file_path).unwrap();
+            file_path
+        }
+    }
+
+    const EXCEL_EPOCH_DAYS: i64 = 25569;
+
+    // 지출 영수증까지 채운 거래
+    fn complete() -> Fixture {
+        Fixture::new(vec![
+            (50_000, 0, "", ""),
+            (0, 20_000, "일반사업", "1"),
+            (0, 5_000, "상시사업", "2"),
+        ])
+    }
+
+    // 통과하지 못한 검증 항목과 문제 수
+    fn failed(report: &Report) -> Vec<(&'static str, usize)> {
+        report
+            .checks
+            .iter()
+            .filter(|check| !check.passed())
+            .map(|check| (check.name, check.issues.len()))
+            .collect()
+    }
+
+    #[test]
+    fn complete_workbook_passes() {
+        let report = validate(&complete().write("complete"), Scope::All).unwrap();
+        assert!(report.passed(), "{report}");
+        assert_eq!(report.checks.len(), 4);
+    }
+
+    #[test]
+    fn missing_budget_sheet_stops() {
+        let fixture = Fixture {
+            missing: Some("2024년도 제1회기 예산안"),
+            ..complete()
+        };
+        let report = validate(&fixture.write("no_budget"), Scope::All).unwrap();
+        assert_eq!(report.checks.len(), 1);
+        assert_eq!(failed(&report), [("필수 sheet", 1)]);
+    }
+
+    #[test]
+    fn missing_month_sheet() {
+        let fixture = Fixture {
+            missing: Some("5월 정산서"),
+            ..complete()
+        };
+        let report = validate(&fixture.write("no_month"), Scope::All).unwrap();
+        assert_eq!(failed(&report), [("필수 sheet", 1)]);
+        assert!(report.checks[0].issues[0].contains("5월 정산서"));
+    }
+
+    #[test]
+    fn wrong_sum_is_reported_once() {
+        let fixture = Fixture {
+            income_error: 1_000,
+            ..complete()
+        };
+        let report = validate(&fixture.write("wrong_sum"), Scope::All).unwrap();
+        assert_eq!(failed(&report), [("합계 및 이월금", 1)]);
+        assert!(report.checks[1].issues[0].contains("'3월 정산서' D2 수입 51000원"));
+    }
+
+    #[test]
+    fn invalid_business_type() {
+        let fixture = Fixture::new(vec![(0, 20_000, "기타", "1")]);
+        let report = validate(&fixture.write("business_type"), Scope::All).unwrap();
+        assert_eq!(failed(&report), [("사업구분", 1)]);
+        assert!(report.checks[2].issues[0].contains("B7"));
+    }
+
+    #[test]
+    fn missing_and_duplicate_receipts() {
+        let fixture = Fixture::new(vec![
+            (0, 20_000, "일반사업", "1"),
+            (0, 5_000, "일반사업", ""),
+            (0, 3_000, "일반사업", "1"),
+            (10_000, 0, "", ""),
+        ]);
+        let file_path = fixture.write("receipts");
+        let report = validate(&file_path, Scope::All).unwrap();
+        assert_eq!(failed(&report), [("영수증 번호", 2)]);
+
+        // 구조 검증에는 영수증 번호가 없음
+        let report = validate(&file_path, Scope::Structure).unwrap();
+        assert!(report.passed(), "{report}");
+        assert_eq!(report.checks.len(), 3);
+    }
+}
@@ -0,0 +1,98 @@
+use std::error::Error;
+
+use rust_xlsxwriter::{ConditionalFormatFormula, Url, Worksheet};
+
+use crate::{
+    audit::Finding,
+    cell_name,
+    format::{Style, Styles},
+    models::{audit::Severity, data::VariantName},
+};
+
+pub fn audit_opinion(
+    worksheet: &mut Worksheet,
+    period: (u16, u8),
+    findings: &[Finding],
+    styles: &Styles,
+) -> Result<(), Box<dyn Error>> {
+    // set column width
+    worksheet
+        .set_column_width(0, 6)?
+        .set_column_width(1, 8)?
+        .set_column_width(2, 13.64)?
+        .set_column_width(3, 22.45)?
+        .set_column_width(4, 54.91)?
+        .set_column_width(5, 43.91)?;
+
+    // Header
+    worksheet
+        .set_row_height(0, 30)?
+        .merge_range(
+            0,
+            0,
+            0,
+            5,
+            &format!("{}년도 제{}회기 감사 의견", period.0, period.1),
+            styles.get(Style::Title),
+        )?
+        .set_row_height(1, 21)?
+        .write_row_with_format(
+            1,
+            0,
+            ["번호", "심각도", "항목", "위치", "내용", "의견"],
+            styles.get(Style::Schema),
+        )?
+        .set_freeze_panes(2, 0)?;
+
+    if findings.is_empty() {
+        worksheet
+            .merge_range(
+                2,
+                0,
+                2,
+                4,
+                "점검에서 발견된 사항이 없습니다.",
+                styles.get(Style::Text),
+            )?
+            .write_with_format(2, 5, "", styles.get(Style::Input))?;
+        return Ok(());
+    }
+
+    let mut row = 2;
+    for (i, finding) in findings.iter().enumerate() {
+        let cell = cell_name(finding.row, finding.col.into());
+        let link = Url::new(format!("internal:'{}'!{cell}", finding.sheet))
+            .set_text(format!("{} {cell}", finding.sheet));
+        worksheet
+            .set_row_height(row, 21)?
+            .write_with_format(row, 0, i as u32 + 1, styles.get(Style::Text))?
+            .write_row_with_format(
+                row,
+                1,
+                [
+                    finding.severity.variant_name(),
+                    finding.check.variant_name(),
+                ],
+                styles.get(Style::Text),
+            )?
+            .write_url_with_format(row, 3, link, styles.get(Style::Link))?
+            .write_with_format(row, 4, &finding.message, styles.get(Style::Text))?
+            .write_with_format(row, 5, "", styles.get(Style::Input))?;
+        row += 1;
+    }
+
+    // 심각한 의견 강조
+    worksheet
+        .add_conditional_format(
+            2,
+            0,
+            row - 1,
+            5,
+            &ConditionalFormatFormula::new()
+                .set_rule(format!("=$B3=\"{}\"", Severity::Critical.variant_name()).as_str())
+                .set_format(styles.get(Style::Alert)),
+        )?
+        .autofilter(1, 0, row - 1, 5)?;
+
+    Ok(())
+}
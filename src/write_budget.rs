@@ -1,81 +1,79 @@
+use std::collections::HashMap;
 use std::error::Error;
 
-use rust_xlsxwriter::{Color, Format, FormatAlign, FormatBorder, Formula, Worksheet};
+use rust_xlsxwriter::{Format, FormatBorder, Worksheet};
 
 use crate::{
-    cell_name,
-    format::format_list,
-    models::data::{BusinessType, VariantName},
+    format::{Borders, Style, Styles},
+    models::{
+        budget::{BudgetPlan, Business},
+        data::{BusinessType, VariantName},
+        template::{Template, Vars},
+    },
+    summary::Summary,
+    table_name, term_months,
+    write_template::{self, merge_or_write},
+    DUES_INCOME_NAME, DUES_NAME, INCOME_TOTAL_NAME, OPENING_BALANCE_NAME,
 };
 
+// 예산안 지출 항목 시작 행
+pub const BUSINESS_ROW: u32 = 10;
+
+// 예산안에 작성되는 사업구분 순서
+pub const BUSINESS_TYPES: [BusinessType; 3] = [
+    BusinessType::OngoingBusiness,
+    BusinessType::GeneralBusiness,
+    BusinessType::PledgedBusiness,
+];
+
+// 예산안에서 각 사업이 시작하는 행
+pub fn business_rows(plan: &BudgetPlan) -> Vec<(&Business, u32)> {
+    let mut rows = Vec::with_capacity(plan.businesses.len());
+    let mut row = BUSINESS_ROW;
+    for business_type in BUSINESS_TYPES.iter() {
+        let start = row;
+        for business in plan.businesses_of(business_type) {
+            rows.push((business, row));
+            row += business.rows();
+        }
+        row = row.max(start + 1);
+    }
+    rows
+}
+
 pub fn write_business(
     worksheet: &mut Worksheet,
     business_type: BusinessType,
+    plan: &BudgetPlan,
     row: u32,
-    cnt: u32,
+    styles: &Styles,
 ) -> Result<u32, Box<dyn Error>> {
-    worksheet.merge_range(
-        row,
-        1,
-        row + cnt - 1,
-        1,
+    let businesses: Vec<&Business> = plan.businesses_of(&business_type).collect();
+    let cnt = businesses
+        .iter()
+        .map(|business| business.rows())
+        .sum::<u32>()
+        .max(1);
+
+    let text_format = styles.form(Style::Input);
+    let num_format = styles.form(Style::InputNumber);
+
+    merge_or_write(
+        worksheet,
+        (row, 1),
+        (row + cnt - 1, 1),
         business_type.variant_name(),
-        &format_list(2)
-            .set_font_size(12)
-            .set_border(FormatBorder::Thin)
-            .set_border_left(FormatBorder::Medium),
+        &Borders::THIN
+            .left(FormatBorder::Medium)
+            .apply(styles.form(Style::Text)),
     )?;
-    // // 개강파티
-    // worksheet
-    //     .write_row_with_format(
-    //         row,
-    //         2,
-    //         ["개강파티", "", "", "", "", ""],
-    //         &format_list(2)
-    //             .set_font_size(12)
-    //             .set_border(FormatBorder::Thin),
-    //     )?
-    //     .set_row_height(row, 27.8)?;
-    // // 간식행사
-    // worksheet
-    //     .merge_range(
-    //         row + 1,
-    //         2,
-    //         row + 2,
-    //         2,
-    //         "간식행사",
-    //         &format_list(2)
-    //             .set_font_size(12)
-    //             .set_border(FormatBorder::Thin),
-    //     )?
-    //     .write_row_with_format(
-    //         row + 1,
-    //         3,
-    //         ["중간고사", "", "", "", ""],
-    //         &format_list(2)
-    //             .set_font_size(12)
-    //             .set_border(FormatBorder::Thin),
-    //     )?
-    //     .set_row_height(row + 1, 27.8)?
-    //     .write_row_with_format(
-    //         row + 2,
-    //         3,
-    //         ["기말고사", "", "", "", ""],
-    //         &format_list(2)
-    //             .set_font_size(12)
-    //             .set_border(FormatBorder::Thin),
-    //     )?
-    //     .set_row_height(row + 2, 27.8)?;
+
+    // 빈 칸으로 작성한 뒤 사업 내용으로 덮어씀
     for r in row..row + cnt {
         worksheet
-            .write_row_with_format(
-                r,
-                2,
-                ["", "", "", "", "", ""],
-                &format_list(2)
-                    .set_font_size(12)
-                    .set_border(FormatBorder::Thin),
-            )?
+            .write_row_with_format(r, 2, ["", ""], text_format)?
+            .write_row_with_format(r, 4, ["", ""], num_format)?
+            .write_row_with_format(r, 6, ["", ""], text_format)?
             .set_row_height(r, 38)?;
         worksheet.write_with_format(
             r,
@@ -84,394 +82,101 @@ pub fn write_business(
             &Format::new().set_border_left(FormatBorder::Medium),
         )?;
     }
-    Ok(row + cnt)
-}
 
-pub fn budget(worksheet: &mut Worksheet, period: (u16, u8)) -> Result<(), Box<dyn Error>> {
-    let schema_format = Format::new()
-        .set_align(FormatAlign::Center)
-        .set_align(FormatAlign::VerticalCenter)
-        .set_font_name("Batangche")
-        .set_font_size(12)
-        .set_background_color(Color::RGB(0xE5E0Ef));
-
-    // set row height
-    worksheet
-        .set_row_height(0, 99)?
-        .set_row_height(1, 7.5)?
-        .set_row_height(2, 22.5)?
-        .set_row_height(3, 7.5)?
-        .set_row_height(4, 22.5)?
-        .set_row_height(5, 22.5)?
-        .set_row_height(6, 26.3)?
-        .set_row_height(7, 15.8)?
-        .set_row_height(8, 26.3)?
-        .set_row_height(9, 27.8)?;
-
-    // set column width
-    worksheet
-        .set_column_width(0, 1.64)?
-        .set_column_width(1, 10.64)?
-        .set_column_width(2, 14.36)?
-        .set_column_width(3, 22.45)?
-        .set_column_width(4, 15.64)?
-        .set_column_width(5, 15.64)?
-        .set_column_width(6, 35.91)?
-        .set_column_width(7, 43.91)?;
-
-    // Header
-    worksheet
-        .merge_range(
-            0,
-            1,
-            0,
-            7,
-            "",
-            &Format::new()
-                .set_align(FormatAlign::Center)
-                .set_align(FormatAlign::VerticalCenter)
-                .set_font_name("Arial")
-                .set_background_color(Color::RGB(0xFCD5B6))
-                .set_border(FormatBorder::Medium),
-        )?
-        .write_rich_string_with_format(
-            0,
-            1,
-            &[
-                (
-                    &Format::new()
-                        .set_font_size(30)
-                        .set_font_name("새굴림")
-                        .set_font_name("Arial")
-                        .set_bold(),
-                    format!("{}년도 제{}회기 예산안\n", period.0, period.1).as_str(),
-                ),
-                (
-                    &Format::new()
-                        .set_font_size(16)
-                        .set_font_name("새굴림")
-                        .set_font_name("Arial")
-                        .set_bold(),
-                    "(OOOO대학 OOOO학과 제OO대 OOOO학생회)\n",
-                ),
-                (
-                    &Format::new()
-                        .set_font_size(16)
-                        .set_font_name("새굴림")
-                        .set_font_name("Arial")
-                        .set_bold(),
-                    "(출범일 - yyyy.mm.dd~yyyy.mm.dd)",
-                ),
-            ],
-            &Format::new()
-                .set_align(FormatAlign::Center)
-                .set_align(FormatAlign::VerticalCenter)
-                .set_background_color(Color::RGB(0xFCD5B6))
-                .set_border(FormatBorder::Medium),
+    let mut r = row;
+    for business in businesses {
+        // 사업명
+        merge_or_write(
+            worksheet,
+            (r, 2),
+            (r + business.rows() - 1, 2),
+            &business.name,
+            text_format,
         )?;
 
-    worksheet
-    .merge_range(1, 1, 1, 7, "",&Format::new())?
-    .merge_range(2, 1, 2, 7, "예산안 작성 전, 반드시 가이드라인 및 작성 예시를 참고해주세요. / 색칠된 칸은 입력하지 마세요. / 양식에 맞추어 작성해주시고, 예산안 원본도 첨부해주세요.", &format_list(2).set_font_size(12).set_bold().set_border(FormatBorder::Thin).set_border_color(Color::White))?;
+        // 지출 상세
+        for (i, line) in business.details.iter().enumerate() {
+            let r = r + i as u32;
+            worksheet
+                .write_with_format(r, 3, &line.detail, text_format)?
+                .write_with_format(r, 4, line.previous, num_format)?
+                .write_with_format(r, 5, line.current, num_format)?
+                .write_with_format(r, 6, &line.basis, text_format)?
+                .write_with_format(r, 7, &line.remarks, text_format)?;
+        }
+        r += business.rows();
+    }
 
-    // 수입
-    let sumif = |n: u8| format!("SUMIF('{n}월 정산서'!C:C, \"학생회비 납부\", '{n}월 정산서'!D:D)");
-    worksheet
-        .merge_range(
-            4,
-            1,
-            4,
-            7,
-            "수입",
-            &schema_format
-                .clone()
-                .set_bold()
-                .set_border(FormatBorder::Medium),
-        )?
-        .merge_range(
-            5,
-            1,
-            5,
-            3,
-            "이월금",
-            &schema_format
-                .clone()
-                .set_border(FormatBorder::Thin)
-                .set_border_left(FormatBorder::Medium),
-        )?
-        .merge_range(
-            5,
-            4,
-            5,
-            6,
-            "학생회비 납부",
-            &schema_format.clone().set_border(FormatBorder::Thin),
-        )?
-        .write_with_format(
-            5,
-            7,
-            "계",
-            &schema_format
-                .clone()
-                .set_font_size(12)
-                .set_border(FormatBorder::Thin)
-                .set_border_right(FormatBorder::Medium),
-        )?
-        .merge_range(
-            6,
-            1,
-            6,
-            3,
-            "",
-            &format_list(5)
-                .set_font_size(12)
-                .set_border(FormatBorder::Medium)
-                .set_border_right(FormatBorder::Thin),
-        )?
-        .merge_range(
-            6,
-            4,
-            6,
-            6,
-            "",
-            &format_list(5)
-                .set_font_size(12)
-                .set_border(FormatBorder::Medium)
-                .set_border_left(FormatBorder::Thin)
-                .set_border_right(FormatBorder::Thin),
-        )?
-        .write_formula_with_format(
-            6,
-            4,
-            Formula::new(
-                {
-                    let mut formula = "=".to_owned();
+    Ok(row + cnt)
+}
 
-                    if period.1 == 1 {
-                        for month in 1..=6 {
-                            formula += sumif(month).as_str();
-                            if month != 6 {
-                                formula += "+";
-                            }
-                        }
-                    } else if period.1 == 2 {
-                        for month in 6..=12 {
-                            formula += sumif(month).as_str();
-                            if month != 12 {
-                                formula += "+";
-                            }
-                        }
-                    } else {
-                        //
-                    }
-                    formula
-                }
-                .as_str(),
-            ),
-            &format_list(5)
-                .set_font_size(12)
-                .set_border(FormatBorder::Medium)
-                .set_border_left(FormatBorder::Thin)
-                .set_border_right(FormatBorder::Thin),
-        )?
-        .write_formula_with_format(
-            6,
-            7,
-            Formula::new("=B7+E7"),
-            &format_list(6)
-                .set_font_size(12)
-                .set_border(FormatBorder::Medium)
-                .set_border_left(FormatBorder::Thin),
-        )?;
+pub fn budget(
+    worksheet: &mut Worksheet,
+    period: (u16, u8),
+    plan: &BudgetPlan,
+    summary: &Summary,
+    organization: &str,
+    template: &Template,
+    styles: &Styles,
+) -> Result<(), Box<dyn Error>> {
+    // 수입 계 (이월금 + 학생회비), 올해 예산 계
+    let income = summary.opening_balance + summary.dues;
+    let budget = plan.current_total() as i64;
+    // 전년도 수입 계, 전년도 예비비 (전년도 수입 계가 없으면 빈 칸)
+    let (previous_income, previous_reserve) = match plan.previous_income {
+        Some(previous_income) => (
+            previous_income.to_string(),
+            (previous_income as i64 - plan.previous_total() as i64).to_string(),
+        ),
+        None => (String::new(), String::new()),
+    };
 
-    let row = write_business(worksheet, BusinessType::OngoingBusiness, 10, 2)?;
-    let row = write_business(worksheet, BusinessType::GeneralBusiness, row, 4)?;
-    let row = write_business(worksheet, BusinessType::PledgedBusiness, row, 2)?;
+    let mut row = BUSINESS_ROW;
+    for business_type in BUSINESS_TYPES {
+        row = write_business(worksheet, business_type, plan, row, styles)?;
+    }
 
-    // 지출
-    worksheet
-        .merge_range(
-            8,
-            1,
-            8,
-            7,
-            "지출",
-            &schema_format
-                .clone()
-                .set_bold()
-                .set_border(FormatBorder::Medium),
-        )?
-        .write_with_format(
-            9,
-            1,
-            "사업구분",
-            &schema_format
-                .clone()
-                .set_border(FormatBorder::Thin)
-                .set_border_left(FormatBorder::Medium)
-                .set_border_bottom(FormatBorder::Medium),
-        )?
-        .write_with_format(
-            9,
-            2,
-            "사업명",
-            &schema_format
-                .clone()
-                .set_border(FormatBorder::Thin)
-                .set_border_bottom(FormatBorder::Medium),
-        )?
-        .write_with_format(
-            9,
-            3,
-            "지출 상세",
-            &schema_format
-                .clone()
-                .set_border(FormatBorder::Thin)
-                .set_border_bottom(FormatBorder::Medium),
-        )?
-        .write_with_format(
-            9,
-            4,
-            format!("{}년도 예산", period.0),
-            &schema_format
-                .clone()
-                .set_border(FormatBorder::Thin)
-                .set_border_bottom(FormatBorder::Medium),
-        )?
-        .write_with_format(
-            9,
-            5,
-            format!("{}년도 예산", period.0),
-            &schema_format
-                .clone()
-                .set_border(FormatBorder::Thin)
-                .set_border_bottom(FormatBorder::Medium),
-        )?
-        .write_with_format(
-            9,
-            6,
-            "산출 근거",
-            &schema_format
-                .clone()
-                .set_border(FormatBorder::Thin)
-                .set_border_bottom(FormatBorder::Medium),
-        )?
-        .write_with_format(
-            9,
-            7,
-            "비고",
-            &schema_format
-                .clone()
-                .set_border(FormatBorder::Thin)
-                .set_border_right(FormatBorder::Medium)
-                .set_border_bottom(FormatBorder::Medium),
-        )?;
+    // 학생회비 수입 (회기가 아니면 빈 수식)
+    let dues_formula = term_months(period.1)
+        .filter(|&month| month != 0)
+        .map(|month| {
+            let table = table_name(month);
+            format!("SUMIF({table}[사업명], \"{DUES_NAME}\", {table}[수입])")
+        })
+        .collect::<Vec<_>>()
+        .join("+");
 
-    worksheet
-        .set_row_height(row, 38)?
-        .merge_range(
-            row,
-            1,
-            row,
-            2,
-            "예비비",
-            &format_list(2)
-                .set_font_size(12)
-                .set_border(FormatBorder::Thin)
-                .set_border_left(FormatBorder::Medium),
-        )?
-        .write_with_format(
-            row,
-            3,
-            "잔액",
-            &format_list(2)
-                .set_font_size(12)
-                .set_border(FormatBorder::Thin),
-        )?
-        .write_formula_with_format(
-            row,
-            4,
-            Formula::new(format!(
-                "={}-SUM({}:{})",
-                cell_name(6, 6),
-                cell_name(10, 4),
-                cell_name(row - 1, 4)
-            )),
-            &format_list(5)
-                .set_font_size(12)
-                .set_border(FormatBorder::Thin),
-        )?
-        .write_formula_with_format(
-            row,
-            5,
-            Formula::new(format!(
-                "={}-SUM({}:{})",
-                cell_name(6, 7),
-                cell_name(10, 5),
-                cell_name(row - 1, 5)
-            )),
-            &format_list(5)
-                .set_font_size(12)
-                .set_border(FormatBorder::Thin),
-        )?
-        .write_with_format(
-            row,
-            6,
-            "",
-            &format_list(2)
-                .set_font_size(12)
-                .set_border(FormatBorder::Thin),
-        )?
-        .write_with_format(
+    let mut vars = Vars::new();
+    vars.set("year", period.0)
+        .set("term", period.1)
+        .set("prev_year", period.0 - 1)
+        .set("organization", organization)
+        .set("dues_name", DUES_NAME)
+        .set("dues_formula", dues_formula)
+        .set("dues", summary.dues)
+        .set("income", income)
+        .set("reserve", income - budget)
+        .set("prev_income", previous_income)
+        .set("prev_reserve", previous_reserve)
+        .set("opening_name", OPENING_BALANCE_NAME)
+        .set("dues_income_name", DUES_INCOME_NAME)
+        .set("income_total_name", INCOME_TOTAL_NAME)
+        .set("business_row", BUSINESS_ROW)
+        .set("end", row);
+    write_template::render(worksheet, template, &vars, &HashMap::new(), styles)?;
+
+    // 이월금
+    if let Some(carry_over) = plan.carry_over {
+        let (row, col) = template.named_cell("carry_over", &vars)?;
+        worksheet.write_with_format(
             row,
-            7,
-            "",
-            &format_list(2)
-                .set_font_size(12)
-                .set_border(FormatBorder::Thin)
-                .set_border_right(FormatBorder::Medium),
-        )?
-        .set_row_height(row + 1, 35)?
-        .merge_range(
-            row + 1,
-            1,
-            row + 1,
-            4,
-            "계",
-            &format_list(3)
-                .set_font_size(12)
-                .set_border(FormatBorder::Medium)
-                .set_border_right(FormatBorder::Thin),
-        )?
-        .write_formula_with_format(
-            row + 1,
-            5,
-            Formula::new(format!("=SUM({}:{})", cell_name(10, 5), cell_name(row, 5))),
-            &format_list(6)
-                .set_font_size(12)
-                .set_border(FormatBorder::Medium)
-                .set_border_left(FormatBorder::Thin)
-                .set_border_right(FormatBorder::Thin),
-        )?
-        .write_with_format(
-            row + 1,
-            6,
-            "",
-            &format_list(3)
-                .set_font_size(12)
-                .set_border(FormatBorder::Medium)
-                .set_border_left(FormatBorder::Thin)
-                .set_border_right(FormatBorder::Thin),
-        )?
-        .write_with_format(
-            row + 1,
-            7,
-            "",
-            &format_list(3)
-                .set_font_size(12)
-                .set_border(FormatBorder::Medium)
-                .set_border_left(FormatBorder::Thin),
+            col,
+            carry_over,
+            &Borders::MEDIUM
+                .right(FormatBorder::Thin)
+                .apply(styles.form(Style::InputNumber)),
         )?;
+    }
 
     Ok(())
 }
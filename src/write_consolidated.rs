@@ -0,0 +1,267 @@
+use std::error::Error;
+
+use rust_xlsxwriter::{
+    Chart, ChartLegendPosition, ChartType, ConditionalFormatFormula, FormatBorder, Formula,
+    Worksheet,
+};
+
+use crate::{
+    batch::Outcome,
+    cell_name,
+    format::{Borders, Style, Styles},
+    models::data::{BusinessType, VariantName},
+    write_budget::BUSINESS_TYPES,
+};
+
+// 학생회 첫 행
+const FIRST_ROW: u32 = 3;
+// 사업구분별 지출 첫 열 (상시사업, 일반사업, 공약사업, 미정)
+const TYPE_COL: u16 = 5;
+// 감사 의견 (발견 사항 수) 열
+const FINDINGS_COL: u16 = 11;
+// 검증 결과 열 (M)
+const STATUS_COL: u16 = 12;
+
+// 학생회별 재정 현황 (월별 정산서 거래로 계산한 값)
+pub fn consolidated(
+    worksheet: &mut Worksheet,
+    period: (u16, u8),
+    outcomes: &[Outcome],
+    styles: &Styles,
+) -> Result<(), Box<dyn Error>> {
+    let sheet_name = worksheet.name();
+
+    // set column width
+    worksheet
+        .set_column_width(0, 1.64)?
+        .set_column_width(1, 28)?
+        .set_column_width(STATUS_COL, 12)?
+        .set_column_width(STATUS_COL + 1, 35.91)?;
+    for col in 2..=11 {
+        worksheet.set_column_width(col, 13.64)?;
+    }
+
+    // Header
+    worksheet.set_row_height(0, 50)?.merge_range(
+        0,
+        1,
+        0,
+        STATUS_COL + 1,
+        &format!("{}년도 제{}회기 학생회 재정 종합", period.0, period.1),
+        &styles
+            .get(Style::Banner)
+            .clone()
+            .set_font_size(20)
+            .set_bold()
+            .set_locked(),
+    )?;
+
+    worksheet.set_row_height(1, 22.5)?.merge_range(
+        1,
+        1,
+        1,
+        STATUS_COL + 1,
+        "금액은 학생회별 월별 정산서의 거래로 계산됩니다. 작성에 실패한 학생회는 오류만 표시됩니다.",
+        styles.form(Style::Notice),
+    )?;
+
+    let mut headers = vec!["학생회", "기초 잔액", "수입", "학생회비 수입"];
+    headers.extend(
+        BUSINESS_TYPES
+            .iter()
+            .map(|business_type| business_type.variant_name()),
+    );
+    headers.extend([
+        BusinessType::Unclassified.label(),
+        "지출 계",
+        "기말 잔액",
+        "감사 의견",
+        "검증 결과",
+        "비고",
+    ]);
+    worksheet.set_row_height(2, 26.3)?.write_row_with_format(
+        2,
+        1,
+        headers,
+        &Borders::THIN
+            .top(FormatBorder::Medium)
+            .bottom(FormatBorder::Medium)
+            .apply(styles.form(Style::Heading)),
+    )?;
+
+    // 학생회별 금액 (실패한 학생회는 빈 칸)
+    let mut row = FIRST_ROW;
+    let mut totals = [0i64; 9];
+    let mut findings = 0;
+    for outcome in outcomes {
+        worksheet.set_row_height(row, 27.8)?.write_with_format(
+            row,
+            1,
+            &outcome.name,
+            styles.form(Style::Text),
+        )?;
+
+        let (status, remarks) = match &outcome.result {
+            Ok(council) => {
+                let summary = &council.generated.summary;
+                let classified: Vec<i64> = BUSINESS_TYPES
+                    .iter()
+                    .map(|business_type| summary.spent_by_type(business_type.variant_name()))
+                    .collect();
+                let mut values = vec![summary.opening_balance, summary.income(), summary.dues];
+                values.extend(&classified);
+                values.extend([
+                    summary.expense() - classified.iter().sum::<i64>(),
+                    summary.expense(),
+                    summary.balance(),
+                ]);
+                for (total, value) in totals.iter_mut().zip(&values) {
+                    *total += value;
+                }
+                findings += council.generated.findings.len();
+                worksheet
+                    .write_row_with_format(row, 2, values, styles.form(Style::Number))?
+                    .write_with_format(
+                        row,
+                        FINDINGS_COL,
+                        council.generated.findings.len() as u32,
+                        styles.form(Style::Text),
+                    )?;
+
+                let validation = &council.validation;
+                match validation.passed() {
+                    true => ("PASS".to_owned(), String::new()),
+                    false => (
+                        "FAIL".to_owned(),
+                        validation
+                            .checks
+                            .iter()
+                            .filter(|check| !check.passed())
+                            .map(|check| format!("{} {}건", check.name, check.issues.len()))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                }
+            }
+            Err(e) => {
+                worksheet
+                    .write_row_with_format(row, 2, [""; 9], styles.form(Style::Number))?
+                    .write_with_format(row, FINDINGS_COL, "", styles.form(Style::Text))?;
+                ("작성 실패".to_owned(), e.clone())
+            }
+        };
+        worksheet
+            .write_with_format(row, STATUS_COL, status, styles.form(Style::Text))?
+            .write_with_format(row, STATUS_COL + 1, remarks, styles.form(Style::Text))?;
+        row += 1;
+    }
+    let last_row = row - 1;
+
+    // 검증 실패, 작성 실패 강조
+    worksheet.add_conditional_format(
+        FIRST_ROW,
+        1,
+        last_row,
+        STATUS_COL + 1,
+        &ConditionalFormatFormula::new()
+            .set_rule("=$M4<>\"PASS\"")
+            .set_format(styles.get(Style::Alert)),
+    )?;
+
+    // 계
+    worksheet
+        .set_row_height(row, 27)?
+        .write_with_format(
+            row,
+            1,
+            "계",
+            &Borders::MEDIUM
+                .right(FormatBorder::Thin)
+                .apply(styles.form(Style::GrayText)),
+        )?
+        .write_row_with_format(
+            row,
+            2,
+            totals.into_iter().zip(2..).map(|(result, col)| {
+                Formula::new(format!(
+                    "=SUM({}:{})",
+                    cell_name(FIRST_ROW, col),
+                    cell_name(last_row, col)
+                ))
+                .set_result(result.to_string())
+            }),
+            &Borders::MEDIUM
+                .left(FormatBorder::Thin)
+                .right(FormatBorder::Thin)
+                .apply(styles.form(Style::GrayFormula)),
+        )?
+        .write_formula_with_format(
+            row,
+            FINDINGS_COL,
+            Formula::new(format!(
+                "=SUM({}:{})",
+                cell_name(FIRST_ROW, FINDINGS_COL.into()),
+                cell_name(last_row, FINDINGS_COL.into())
+            ))
+            .set_result(findings.to_string()),
+            &Borders::MEDIUM
+                .left(FormatBorder::Thin)
+                .right(FormatBorder::Thin)
+                .apply(styles.form(Style::GrayText)),
+        )?
+        .write_row_with_format(
+            row,
+            STATUS_COL,
+            ["", ""],
+            &Borders::MEDIUM
+                .left(FormatBorder::Thin)
+                .apply(styles.form(Style::GrayText)),
+        )?;
+
+    let categories = (sheet_name.as_str(), FIRST_ROW, 1, last_row, 1);
+
+    // 학생회별 수입, 지출
+    let mut chart = Chart::new(ChartType::Column);
+    for (name, col) in [("수입", 3), ("지출", TYPE_COL + 4)] {
+        chart
+            .add_series()
+            .set_name(name)
+            .set_categories(categories)
+            .set_values((sheet_name.as_str(), FIRST_ROW, col, last_row, col));
+    }
+    chart.title().set_name("학생회별 수입 및 지출");
+    chart.legend().set_position(ChartLegendPosition::Bottom);
+    worksheet.insert_chart(row + 2, 1, &chart)?;
+
+    // 학생회별 사업구분별 지출
+    let mut chart = Chart::new(ChartType::ColumnStacked);
+    for col in TYPE_COL..TYPE_COL + 4 {
+        chart
+            .add_series()
+            .set_name((sheet_name.as_str(), 2, col))
+            .set_categories(categories)
+            .set_values((sheet_name.as_str(), FIRST_ROW, col, last_row, col));
+    }
+    chart.title().set_name("학생회별 사업구분별 지출");
+    chart.legend().set_position(ChartLegendPosition::Bottom);
+    worksheet.insert_chart(row + 2, 5, &chart)?;
+
+    // 학생회별 기말 잔액
+    let mut chart = Chart::new(ChartType::Bar);
+    chart
+        .add_series()
+        .set_name("기말 잔액")
+        .set_categories(categories)
+        .set_values((
+            sheet_name.as_str(),
+            FIRST_ROW,
+            TYPE_COL + 5,
+            last_row,
+            TYPE_COL + 5,
+        ));
+    chart.title().set_name("학생회별 기말 잔액");
+    chart.legend().set_hidden();
+    worksheet.insert_chart(row + 2, 10, &chart)?;
+
+    Ok(())
+}
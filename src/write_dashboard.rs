@@ -0,0 +1,150 @@
+use std::error::Error;
+
+use rust_xlsxwriter::{
+    Chart, ChartDataLabel, ChartLegendPosition, ChartMarker, ChartMarkerType, ChartType, Formula,
+    Worksheet,
+};
+
+use crate::{
+    cell_name,
+    format::{Style, Styles},
+    models::data::{BusinessType, VariantName},
+    month_name,
+    summary::Summary,
+    table_name, term_months,
+    write_budget::BUSINESS_TYPES,
+};
+
+// 정산서 sheet의 월별 첫 행
+const ACCOUNT_MONTH_ROW: u32 = 4;
+
+pub fn dashboard(
+    worksheet: &mut Worksheet,
+    period: (u16, u8),
+    summary: &Summary,
+    styles: &Styles,
+) -> Result<(), Box<dyn Error>> {
+    let sheet_name = worksheet.name();
+    let account_sheet = format!("{}년도 제{}회기 정산서", period.0, period.1);
+    let months = term_months(period.1);
+    let last_row = ACCOUNT_MONTH_ROW + months.clone().count() as u32 - 1;
+
+    // set column width
+    worksheet
+        .set_column_width(0, 1.64)?
+        .set_column_width(1, 14.36)?
+        .set_column_width(2, 15.64)?;
+
+    // Header
+    worksheet.set_row_height(0, 40)?.merge_range(
+        0,
+        1,
+        0,
+        16,
+        &format!("{}년도 제{}회기 재정 현황", period.0, period.1),
+        &styles
+            .get(Style::Banner)
+            .clone()
+            .set_font_size(20)
+            .set_bold()
+            .set_locked(),
+    )?;
+
+    // 사업구분별 지출 (원형 차트 원본)
+    worksheet.write_row_with_format(2, 1, ["사업구분", "지출"], styles.get(Style::Schema))?;
+    let mut row = 3;
+    for business_type in BUSINESS_TYPES.iter() {
+        let name = business_type.variant_name();
+        worksheet
+            .write_with_format(row, 1, name, styles.get(Style::LavenderText))?
+            .write_formula_with_format(
+                row,
+                2,
+                Formula::new(format!(
+                    "={}",
+                    months
+                        .clone()
+                        .map(|month| {
+                            let table = table_name(month);
+                            format!("SUMIF({table}[사업구분], \"{name}\", {table}[지출])")
+                        })
+                        .collect::<Vec<_>>()
+                        .join("+")
+                ))
+                .set_result(summary.spent_by_type(name).to_string()),
+                styles.get(Style::GrayFormula),
+            )?;
+        row += 1;
+    }
+    // 지출 합계에서 분류된 지출을 뺀 나머지
+    let unclassified = summary.expense()
+        - BUSINESS_TYPES
+            .iter()
+            .map(|business_type| summary.spent_by_type(business_type.variant_name()))
+            .sum::<i64>();
+    worksheet
+        .write_with_format(
+            row,
+            1,
+            BusinessType::Unclassified.label(),
+            styles.get(Style::LavenderText),
+        )?
+        .write_formula_with_format(
+            row,
+            2,
+            Formula::new(format!(
+                "={}-SUM({}:{})",
+                months
+                    .clone()
+                    .map(|month| month_name("지출", month))
+                    .collect::<Vec<_>>()
+                    .join("+"),
+                cell_name(3, 2),
+                cell_name(row - 1, 2)
+            ))
+            .set_result(unclassified.to_string()),
+            styles.get(Style::GrayFormula),
+        )?;
+    let type_last_row = row;
+
+    // 월별 수입, 지출
+    let mut chart = Chart::new(ChartType::Column);
+    chart
+        .add_series()
+        .set_name("수입")
+        .set_categories((account_sheet.as_str(), ACCOUNT_MONTH_ROW, 1, last_row, 1))
+        .set_values((account_sheet.as_str(), ACCOUNT_MONTH_ROW, 2, last_row, 2));
+    chart
+        .add_series()
+        .set_name("지출")
+        .set_categories((account_sheet.as_str(), ACCOUNT_MONTH_ROW, 1, last_row, 1))
+        .set_values((account_sheet.as_str(), ACCOUNT_MONTH_ROW, 3, last_row, 3));
+    chart.title().set_name("월별 수입 및 지출");
+    chart.legend().set_position(ChartLegendPosition::Bottom);
+    worksheet.insert_chart(2, 4, &chart)?;
+
+    // 월별 잔액
+    let mut chart = Chart::new(ChartType::Line);
+    chart
+        .add_series()
+        .set_name("총잔액")
+        .set_categories((account_sheet.as_str(), ACCOUNT_MONTH_ROW, 1, last_row, 1))
+        .set_values((account_sheet.as_str(), ACCOUNT_MONTH_ROW, 5, last_row, 5))
+        .set_marker(ChartMarker::new().set_type(ChartMarkerType::Circle));
+    chart.title().set_name("월별 잔액 추이");
+    chart.legend().set_hidden();
+    worksheet.insert_chart(2, 12, &chart)?;
+
+    // 사업구분별 지출 비율
+    let mut chart = Chart::new(ChartType::Pie);
+    chart
+        .add_series()
+        .set_name("사업구분별 지출")
+        .set_categories((sheet_name.as_str(), 3, 1, type_last_row, 1))
+        .set_values((sheet_name.as_str(), 3, 2, type_last_row, 2))
+        .set_data_label(ChartDataLabel::new().show_percentage());
+    chart.title().set_name("사업구분별 지출");
+    worksheet.insert_chart(18, 4, &chart)?;
+
+    Ok(())
+}
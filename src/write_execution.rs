@@ -0,0 +1,209 @@
+use std::error::Error;
+
+use rust_xlsxwriter::{ConditionalFormatFormula, FormatBorder, Formula, Worksheet};
+
+use crate::{
+    budget_name, cell_name,
+    format::{Borders, Style, Styles},
+    models::{budget::BudgetPlan, data::VariantName},
+    summary::{rate, Summary},
+    table_name, term_months,
+    write_budget::business_rows,
+};
+
+pub fn execution(
+    worksheet: &mut Worksheet,
+    period: (u16, u8),
+    plan: &BudgetPlan,
+    summary: &Summary,
+    styles: &Styles,
+) -> Result<(), Box<dyn Error>> {
+    // set column width
+    worksheet
+        .set_column_width(0, 1.64)?
+        .set_column_width(1, 10.64)?
+        .set_column_width(2, 22.45)?
+        .set_column_width(3, 15.64)?
+        .set_column_width(4, 15.64)?
+        .set_column_width(5, 15.64)?
+        .set_column_width(6, 12)?
+        .set_column_width(7, 35.91)?;
+
+    // Header
+    worksheet.set_row_height(0, 50)?.merge_range(
+        0,
+        1,
+        0,
+        7,
+        &format!("{}년도 제{}회기 예산 대비 집행 현황", period.0, period.1),
+        &styles
+            .get(Style::Banner)
+            .clone()
+            .set_font_size(20)
+            .set_bold()
+            .set_locked(),
+    )?;
+
+    worksheet.set_row_height(1, 22.5)?.merge_range(
+        1,
+        1,
+        1,
+        7,
+        "집행액은 월별 정산서의 사업구분 및 사업명이 예산안과 같은 지출만 합산됩니다.",
+        styles.form(Style::Notice),
+    )?;
+
+    worksheet.set_row_height(2, 26.3)?.write_row_with_format(
+        2,
+        1,
+        [
+            "사업구분",
+            "사업명",
+            "예산",
+            "집행액",
+            "차액",
+            "집행률",
+            "비고",
+        ],
+        &Borders::THIN
+            .top(FormatBorder::Medium)
+            .bottom(FormatBorder::Medium)
+            .apply(styles.form(Style::Heading)),
+    )?;
+
+    // 사업별 예산, 집행액
+    let mut row = 3;
+    let (mut budget_total, mut spent_total) = (0, 0);
+    for (idx, (business, _)) in business_rows(plan).into_iter().enumerate() {
+        let budget = business.current_total() as i64;
+        let spent_result = summary.spent(&business.business_type, &business.name);
+        budget_total += budget;
+        spent_total += spent_result;
+
+        let business_type = business.business_type.variant_name();
+        let business_name = business.name.replace('"', "\"\"");
+        let spent = term_months(period.1)
+            .map(|month| {
+                let table = table_name(month);
+                format!(
+                    "SUMIFS({table}[지출], {table}[사업구분], \"{business_type}\", {table}[사업명], \"{business_name}\")"
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("+");
+
+        worksheet
+            .set_row_height(row, 27.8)?
+            .write_row_with_format(
+                row,
+                1,
+                [business_type, business.name.as_str()],
+                styles.form(Style::Text),
+            )?
+            .write_row_with_format(
+                row,
+                3,
+                [
+                    Formula::new(format!("=SUM({})", budget_name(idx)))
+                        .set_result(budget.to_string()),
+                    Formula::new(format!("={spent}")).set_result(spent_result.to_string()),
+                    Formula::new(format!("={}-{}", cell_name(row, 3), cell_name(row, 4)))
+                        .set_result((budget - spent_result).to_string()),
+                ],
+                styles.form(Style::GrayFormula),
+            )?
+            .write_formula_with_format(
+                row,
+                6,
+                Formula::new(format!(
+                    "=IF({0}=0,0,{1}/{0})",
+                    cell_name(row, 3),
+                    cell_name(row, 4)
+                ))
+                .set_result(rate(spent_result, budget).to_string()),
+                styles.form(Style::Rate),
+            )?
+            .write_with_format(row, 7, "", styles.form(Style::Input))?;
+
+        row += 1;
+    }
+
+    // 예산안에 사업이 없으면 빈 행 작성
+    if row == 3 {
+        worksheet.set_row_height(row, 27.8)?.write_row_with_format(
+            row,
+            1,
+            ["", "", "", "", "", "", ""],
+            styles.form(Style::Text),
+        )?;
+        row += 1;
+    } else {
+        // 예산 초과 사업 강조
+        worksheet.add_conditional_format(
+            3,
+            1,
+            row - 1,
+            7,
+            &ConditionalFormatFormula::new()
+                .set_rule("=$E4>$D4")
+                .set_format(styles.get(Style::Alert)),
+        )?;
+    }
+
+    // 계
+    worksheet
+        .set_row_height(row, 27)?
+        .merge_range(
+            row,
+            1,
+            row,
+            2,
+            "계",
+            &Borders::MEDIUM
+                .right(FormatBorder::Thin)
+                .apply(styles.form(Style::GrayText)),
+        )?
+        .write_row_with_format(
+            row,
+            3,
+            [budget_total, spent_total, budget_total - spent_total]
+                .into_iter()
+                .zip(3..=5)
+                .map(|(result, col)| {
+                    Formula::new(format!(
+                        "=SUM({}:{})",
+                        cell_name(3, col),
+                        cell_name(row - 1, col)
+                    ))
+                    .set_result(result.to_string())
+                }),
+            &Borders::MEDIUM
+                .left(FormatBorder::Thin)
+                .right(FormatBorder::Thin)
+                .apply(styles.form(Style::GrayFormula)),
+        )?
+        .write_formula_with_format(
+            row,
+            6,
+            Formula::new(format!(
+                "=IF({0}=0,0,{1}/{0})",
+                cell_name(row, 3),
+                cell_name(row, 4)
+            ))
+            .set_result(rate(spent_total, budget_total).to_string()),
+            &Borders::MEDIUM
+                .left(FormatBorder::Thin)
+                .right(FormatBorder::Thin)
+                .apply(styles.form(Style::Rate)),
+        )?
+        .write_with_format(
+            row,
+            7,
+            "",
+            &Borders::MEDIUM
+                .left(FormatBorder::Thin)
+                .apply(styles.form(Style::GrayText)),
+        )?;
+
+    Ok(())
+}
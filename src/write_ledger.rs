@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+
+use rust_xlsxwriter::{ExcelDateTime, FormatBorder, Formula, Worksheet};
+
+use crate::{
+    cell_name,
+    format::{Borders, Style, Styles},
+    models::data::{BusinessType, Data},
+    write_budget::BUSINESS_TYPES,
+};
+
+// 사업구분 정렬 순서 (미정은 마지막)
+fn type_order(business_type: &BusinessType) -> usize {
+    BUSINESS_TYPES
+        .iter()
+        .position(|t| t == business_type)
+        .unwrap_or(BUSINESS_TYPES.len())
+}
+
+// 사업구분, 사업명별 거래 묶음
+pub fn group_by_business(
+    month_data_list: &[(u8, Vec<Data>)],
+) -> BTreeMap<(usize, String), Vec<&Data>> {
+    let mut groups: BTreeMap<(usize, String), Vec<&Data>> = BTreeMap::new();
+    for data in month_data_list.iter().flat_map(|(_, data_list)| data_list) {
+        groups
+            .entry((
+                type_order(&data.business_type),
+                data.business_name.clone().unwrap_or_default(),
+            ))
+            .or_default()
+            .push(data);
+    }
+    groups
+}
+
+pub fn ledger(
+    worksheet: &mut Worksheet,
+    period: (u16, u8),
+    month_data_list: &[(u8, Vec<Data>)],
+    styles: &Styles,
+) -> Result<(), Box<dyn Error>> {
+    // set column width
+    worksheet
+        .set_column_width(0, 11.91)?
+        .set_column_width(1, 11.91)?
+        .set_column_width(2, 17.36)?
+        .set_column_width(3, 13)?
+        .set_column_width(4, 13)?
+        .set_column_width(5, 54.91)?
+        .set_column_width(6, 17.36)?;
+
+    // Header
+    worksheet
+        .set_row_height(0, 30)?
+        .merge_range(
+            0,
+            0,
+            0,
+            6,
+            &format!("{}년도 제{}회기 사업별 장부", period.0, period.1),
+            styles.get(Style::Title),
+        )?
+        .set_row_height(1, 21)?
+        .write_row_with_format(
+            1,
+            0,
+            [
+                "날짜",
+                "사업구분",
+                "사업명",
+                "수입",
+                "지출",
+                "비고",
+                "영수증번호",
+            ],
+            styles.get(Style::Schema),
+        )?
+        .set_freeze_panes(2, 0)?;
+
+    let subtotal_format = styles.get(Style::Total);
+    let total_format = Borders::THIN
+        .top(FormatBorder::Double)
+        .apply(styles.get(Style::Total));
+
+    let mut row = 2;
+    let mut type_totals: Vec<u32> = Vec::new(); // 사업구분 계 행
+    let mut business_totals: Vec<u32> = Vec::new(); // 사업명 소계 행
+    let (mut type_sums, mut total_sums) = ([0i64; 2], [0i64; 2]); // 캐시 값
+    let groups = group_by_business(month_data_list);
+    let mut iter = groups.iter().peekable();
+
+    while let Some(((order, business_name), data_list)) = iter.next() {
+        let type_name = BUSINESS_TYPES
+            .get(*order)
+            .unwrap_or(&BusinessType::Unclassified)
+            .label();
+
+        let first = row;
+        let mut sums = [0i64; 2]; // 수입, 지출
+        for data in data_list {
+            sums[0] += data.cash_in as i64;
+            sums[1] += data.cash_out as i64;
+            let datetime = ExcelDateTime::from_ymd(data.date.year, data.date.month, data.date.day)?;
+            worksheet
+                .write_with_format(row, 0, &datetime, styles.get(Style::Date))?
+                .write_row_with_format(row, 1, [type_name, business_name], styles.get(Style::Text))?
+                .write_row_with_format(
+                    row,
+                    3,
+                    [data.cash_in, data.cash_out],
+                    styles.get(Style::Number),
+                )?
+                .write_row_with_format(
+                    row,
+                    5,
+                    [
+                        data.remarks.clone().unwrap_or_default(),
+                        data.receipt_num.clone().unwrap_or_default(),
+                    ],
+                    styles.get(Style::Text),
+                )?;
+            row += 1;
+        }
+
+        // 사업명 소계
+        worksheet
+            .merge_range(
+                row,
+                0,
+                row,
+                2,
+                &format!(
+                    "{} 소계",
+                    if business_name.is_empty() {
+                        type_name
+                    } else {
+                        business_name
+                    }
+                ),
+                styles.get(Style::LavenderText),
+            )?
+            .write_row_with_format(
+                row,
+                3,
+                (3..=4).map(|col| {
+                    Formula::new(format!(
+                        "=SUM({}:{})",
+                        cell_name(first, col),
+                        cell_name(row - 1, col)
+                    ))
+                    .set_result(sums[col as usize - 3].to_string())
+                }),
+                subtotal_format,
+            )?
+            .write_row_with_format(row, 5, ["", ""], styles.get(Style::LavenderText))?;
+        business_totals.push(row);
+        type_sums[0] += sums[0];
+        type_sums[1] += sums[1];
+        row += 1;
+
+        // 사업구분 계
+        if iter.peek().map(|((next, _), _)| next) != Some(order) {
+            worksheet
+                .merge_range(
+                    row,
+                    0,
+                    row,
+                    2,
+                    &format!("{type_name} 계"),
+                    &styles.get(Style::LavenderText).clone().set_bold(),
+                )?
+                .write_row_with_format(
+                    row,
+                    3,
+                    (3..=4).map(|col| {
+                        Formula::new(format!(
+                            "={}",
+                            business_totals
+                                .iter()
+                                .map(|&r| cell_name(r, col))
+                                .collect::<Vec<_>>()
+                                .join("+")
+                        ))
+                        .set_result(type_sums[col as usize - 3].to_string())
+                    }),
+                    subtotal_format,
+                )?
+                .write_row_with_format(row, 5, ["", ""], styles.get(Style::LavenderText))?;
+            type_totals.push(row);
+            business_totals.clear();
+            total_sums[0] += type_sums[0];
+            total_sums[1] += type_sums[1];
+            type_sums = [0; 2];
+            row += 1;
+        }
+    }
+
+    // 총계
+    worksheet
+        .merge_range(
+            row,
+            0,
+            row,
+            2,
+            "총계",
+            &Borders::THIN
+                .top(FormatBorder::Double)
+                .apply(styles.get(Style::LavenderText))
+                .set_bold(),
+        )?
+        .write_row_with_format(
+            row,
+            3,
+            (3..=4).map(|col| {
+                Formula::new(match type_totals.is_empty() {
+                    true => "=0".to_owned(),
+                    false => format!(
+                        "={}",
+                        type_totals
+                            .iter()
+                            .map(|&r| cell_name(r, col))
+                            .collect::<Vec<_>>()
+                            .join("+")
+                    ),
+                })
+                .set_result(total_sums[col as usize - 3].to_string())
+            }),
+            &total_format,
+        )?
+        .write_row_with_format(row, 5, ["", ""], &total_format)?;
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use rust_xlsxwriter::{Formula, Worksheet};
+
+use crate::{
+    format::{Style, Styles},
+    models::{data::Data, template::Template, theme::Print},
+    month_name, month_vars, print_setup, sheet_template,
+    summary::{MonthSummary, Summary},
+    table_name, total_row, write_data_in_sheet, Columns, InputLists, OPENING_BALANCE_NAME,
+};
+
+// 월별 정산서 작성에 필요한 공통 값
+pub struct MonthContext {
+    pub input_lists: InputLists,
+    pub columns: Columns,
+    pub styles: Styles,
+    pub template: Template,
+    pub print: Print,
+    pub organization: String,
+}
+
+// 월별 정산서 한 장 (이월금은 전월 잔액의 정의된 이름을 참조)
+pub fn month_sheet(
+    month: u8,
+    data_list: &Vec<Data>,
+    summary: &MonthSummary,
+    prev_month: Option<u8>,
+    context: &MonthContext,
+) -> Result<Worksheet, Box<dyn Error>> {
+    let (columns, styles) = (context.columns, &context.styles);
+    let sheet_name = month.to_string() + "월 정산서";
+    let mut worksheet = Worksheet::new();
+    sheet_template(
+        &mut worksheet,
+        sheet_name.as_str(),
+        &context.template,
+        columns,
+        styles,
+    )?;
+
+    // write data
+    write_data_in_sheet(
+        &mut worksheet,
+        data_list,
+        summary,
+        &context.input_lists,
+        columns,
+        styles,
+    )?;
+
+    // write schema formula (위치는 양식의 names)
+    let vars = month_vars(&sheet_name, columns);
+    let (income_row, income_col) = context.template.named_cell("income", &vars)?;
+    let (expense_row, expense_col) = context.template.named_cell("expense", &vars)?;
+    let (carry_over_row, carry_over_col) = context.template.named_cell("carry_over", &vars)?;
+    worksheet
+        // 수입
+        .write_formula_with_format(
+            income_row,
+            income_col,
+            Formula::new(format!("=SUM({}[수입])", table_name(month)))
+                .set_result(summary.income.to_string()),
+            styles.get(Style::GrayFormula),
+        )?
+        // 지출
+        .write_formula_with_format(
+            expense_row,
+            expense_col,
+            Formula::new(format!("=SUM({}[지출])", table_name(month)))
+                .set_result(summary.expense.to_string()),
+            styles.get(Style::GrayFormula),
+        )?;
+    // 이월금
+    let (carry_over, remarks) = match prev_month {
+        None => (
+            OPENING_BALANCE_NAME.to_owned(),
+            "전단위 인수인계 금액".to_owned(),
+        ),
+        Some(prev_month) => (
+            month_name("잔액", prev_month),
+            format!("{prev_month}월 이월금"),
+        ),
+    };
+    worksheet
+        .write_formula_with_format(
+            carry_over_row,
+            carry_over_col,
+            Formula::new(format!("={carry_over}")).set_result(summary.carry_over.to_string()),
+            styles.get(Style::GrayFormula),
+        )?
+        .write_with_format(
+            carry_over_row,
+            columns.remarks(),
+            remarks,
+            styles.get(Style::GrayText),
+        )?;
+
+    // 인쇄 설정 (계 행까지)
+    print_setup(
+        &mut worksheet,
+        &context.print,
+        &context.organization,
+        Some((total_row(data_list.len() as u32), columns.receipt_num())),
+    )?;
+
+    Ok(worksheet)
+}
+
+// 월 순서대로 작성
+pub fn month_sheets(
+    month_data_list: &[(u8, Vec<Data>)],
+    summary: &Summary,
+    context: &MonthContext,
+) -> Result<Vec<Worksheet>, Box<dyn Error>> {
+    let mut worksheets = Vec::with_capacity(month_data_list.len());
+    let mut prev_month = None; // 작월 이월금을 가져오기 위해 사용
+    for ((month, data_list), month_summary) in month_data_list.iter().zip(&summary.months) {
+        worksheets.push(month_sheet(
+            *month,
+            data_list,
+            month_summary,
+            prev_month,
+            context,
+        )?);
+        prev_month = Some(*month);
+    }
+    Ok(worksheets)
+}
+
+// 월별로 동시에 작성, 결과는 월 순서 유지
+pub async fn month_sheets_concurrent(
+    month_data_list: &[(u8, Vec<Data>)],
+    summary: &Summary,
+    context: Arc<MonthContext>,
+) -> Result<Vec<Worksheet>, Box<dyn Error>> {
+    let mut handles = Vec::with_capacity(month_data_list.len());
+    let mut prev_month = None;
+    for ((month, data_list), month_summary) in month_data_list.iter().zip(&summary.months) {
+        let (month, data_list) = (*month, data_list.clone());
+        let month_summary = month_summary.clone();
+        let context = Arc::clone(&context);
+        handles.push(tokio::task::spawn_blocking(move || {
+            month_sheet(month, &data_list, &month_summary, prev_month, &context)
+                .map_err(|e| e.to_string())
+        }));
+        prev_month = Some(month);
+    }
+
+    let mut worksheets = Vec::with_capacity(handles.len());
+    for handle in handles {
+        worksheets.push(handle.await??);
+    }
+    Ok(worksheets)
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use rust_xlsxwriter::{Format, FormatBorder, Formula, Worksheet};
+
+use crate::{
+    format::{parse_color, Borders, Style, Styles},
+    models::template::{Border, BorderSpec, Cell, CellFormat, Merge, Template, Value, Vars},
+};
+
+// 양식대로 sheet 작성 (blocks: 반복 블록 이름별 항목 변수)
+pub fn render(
+    worksheet: &mut Worksheet,
+    template: &Template,
+    vars: &Vars,
+    blocks: &HashMap<&str, Vec<Vars>>,
+    styles: &Styles,
+) -> Result<(), Box<dyn Error>> {
+    let mut vars = vars.clone();
+
+    for column in &template.columns {
+        worksheet.set_column_width(column.col.eval(&vars)? as u16, column.width)?;
+    }
+    if let Some(height) = template.default_row_height {
+        worksheet.set_default_row_height(height);
+    }
+
+    // 반복 행
+    for block in &template.blocks {
+        let items = blocks
+            .get(block.name.as_str())
+            .ok_or_else(|| format!("알 수 없는 반복 블록: {}", block.name))?;
+        let mut row = block.row.eval(&vars)?;
+        for item in items {
+            let mut item_vars = vars.extend(item);
+            item_vars.set("row", row);
+            if let Some(height) = block.height {
+                for r in row..row + block.rows {
+                    worksheet.set_row_height(r, height)?;
+                }
+            }
+            for merge in &block.merges {
+                write_merge(worksheet, merge, &item_vars, template.form, styles)?;
+            }
+            for cell in &block.cells {
+                write_cell(worksheet, cell, &item_vars, template.form, styles)?;
+            }
+            row += block.rows;
+        }
+        vars.set(&format!("{}_end", block.name), row);
+    }
+
+    for row in &template.rows {
+        worksheet.set_row_height(row.row.eval(&vars)?, row.height)?;
+    }
+    for merge in &template.merges {
+        write_merge(worksheet, merge, &vars, template.form, styles)?;
+    }
+    for cell in &template.cells {
+        write_cell(worksheet, cell, &vars, template.form, styles)?;
+    }
+
+    Ok(())
+}
+
+fn write_merge(
+    worksheet: &mut Worksheet,
+    merge: &Merge,
+    vars: &Vars,
+    form: bool,
+    styles: &Styles,
+) -> Result<(), Box<dyn Error>> {
+    let [first_row, first_col, last_row, last_col] = &merge.range;
+    let (first_row, first_col) = (first_row.eval(vars)?, first_col.eval(vars)? as u16);
+    let (last_row, last_col) = (last_row.eval(vars)?, last_col.eval(vars)? as u16);
+    let value = vars.fill(&merge.value)?;
+    let format = cell_format(&merge.format, form, styles)?;
+    merge_or_write(
+        worksheet,
+        (first_row, first_col),
+        (last_row, last_col),
+        &value,
+        &format,
+    )
+}
+
+// 한 칸짜리 병합은 허용되지 않음
+pub fn merge_or_write(
+    worksheet: &mut Worksheet,
+    (first_row, first_col): (u32, u16),
+    (last_row, last_col): (u32, u16),
+    text: &str,
+    format: &Format,
+) -> Result<(), Box<dyn Error>> {
+    if (first_row, first_col) == (last_row, last_col) {
+        worksheet.write_with_format(first_row, first_col, text, format)?;
+    } else {
+        worksheet.merge_range(first_row, first_col, last_row, last_col, text, format)?;
+    }
+    Ok(())
+}
+
+fn write_cell(
+    worksheet: &mut Worksheet,
+    cell: &Cell,
+    vars: &Vars,
+    form: bool,
+    styles: &Styles,
+) -> Result<(), Box<dyn Error>> {
+    let [row, col] = &cell.at;
+    let (row, col) = (row.eval(vars)?, col.eval(vars)? as u16);
+    let format = cell_format(&cell.format, form, styles)?;
+
+    if let Some(name) = &cell.when {
+        if vars.get(name).is_none_or(str::is_empty) {
+            worksheet.write_with_format(row, col, "", &format)?;
+            return Ok(());
+        }
+    }
+
+    if let Some(segments) = &cell.rich {
+        let texts = segments
+            .iter()
+            .map(|segment| vars.fill(&segment.text))
+            .collect::<Result<Vec<_>, _>>()?;
+        let rich: Vec<(&Format, &str)> = segments
+            .iter()
+            .zip(&texts)
+            .map(|(segment, text)| (style_of(segment.style, form, styles), text.as_str()))
+            .collect();
+        worksheet.write_rich_string_with_format(row, col, &rich, &format)?;
+    } else if let Some(formula) = &cell.formula {
+        let mut formula = Formula::new(vars.fill(formula)?);
+        if let Some(result) = &cell.result {
+            formula = formula.set_result(vars.fill(result)?);
+        }
+        worksheet.write_formula_with_format(row, col, formula, &format)?;
+    } else if let Some(values) = &cell.values {
+        let values = values
+            .iter()
+            .map(|value| vars.fill(value))
+            .collect::<Result<Vec<_>, _>>()?;
+        worksheet.write_row_with_format(row, col, values, &format)?;
+    } else {
+        match &cell.value {
+            Some(Value::Number(number)) => {
+                worksheet.write_with_format(row, col, *number, &format)?;
+            }
+            Some(Value::Text(text)) => {
+                worksheet.write_with_format(row, col, vars.fill(text)?, &format)?;
+            }
+            None => {
+                worksheet.write_with_format(row, col, "", &format)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn style_of(style: Style, form: bool, styles: &Styles) -> &Format {
+    match form {
+        true => styles.form(style),
+        false => styles.get(style),
+    }
+}
+
+// 기본 서식에 테두리, 굵게 적용
+fn cell_format(spec: &CellFormat, form: bool, styles: &Styles) -> Result<Format, Box<dyn Error>> {
+    let mut format = match spec.style {
+        Some(style) => style_of(style, form, styles).clone(),
+        None => Format::new(),
+    };
+    if let Some(borders) = &spec.borders {
+        format = borders_of(borders).apply(&format);
+    }
+    if let Some(color) = &spec.border_color {
+        format = format.set_border_color(parse_color(color)?);
+    }
+    if spec.bold {
+        format = format.set_bold();
+    }
+    Ok(format)
+}
+
+fn borders_of(spec: &BorderSpec) -> Borders {
+    let border = |border: Border| match border {
+        Border::None => FormatBorder::None,
+        Border::Thin => FormatBorder::Thin,
+        Border::Medium => FormatBorder::Medium,
+    };
+    let mut borders = Borders::all(spec.all.map_or(FormatBorder::None, border));
+    if let Some(top) = spec.top {
+        borders = borders.top(border(top));
+    }
+    if let Some(bottom) = spec.bottom {
+        borders = borders.bottom(border(bottom));
+    }
+    if let Some(left) = spec.left {
+        borders = borders.left(border(left));
+    }
+    if let Some(right) = spec.right {
+        borders = borders.right(border(right));
+    }
+    borders
+}
@@ -0,0 +1,51 @@
+// 통합 테스트에서 함께 쓰는 거래내역, 회기 요약, 임시 workbook
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use transaction_manager::models::budget::BudgetPlan;
+use transaction_manager::models::data::{BusinessType, Data, DataBuilder, Date};
+use transaction_manager::summary::Summary;
+
+pub fn data(month: u8, cash_in: u32, cash_out: u32, business_type: BusinessType) -> Data {
+    DataBuilder::new()
+        .date(Date {
+            year: 2024,
+            month,
+            day: 1,
+        })
+        .business_type(business_type)
+        .cash_in(cash_in)
+        .cash_out(cash_out)
+        .balance(0)
+        .build()
+        .unwrap()
+}
+
+// 6월 수입 1,000,000 지출 250,000, 7월 지출 30,000 (미정 2건)
+pub fn summary() -> Summary {
+    let month_data_list = vec![
+        (
+            6,
+            vec![
+                data(6, 1_000_000, 0, BusinessType::Unclassified),
+                data(6, 0, 250_000, BusinessType::GeneralBusiness),
+            ],
+        ),
+        (7, vec![data(7, 0, 30_000, BusinessType::Unclassified)]),
+    ];
+    let plan = BudgetPlan {
+        carry_over: Some(500_000),
+        ..BudgetPlan::default()
+    };
+    Summary::new(&month_data_list, &plan)
+}
+
+// 테스트마다 다른 임시 파일 (workbook_{pid}_{name})
+pub fn workbook(name: &str) -> PathBuf {
+    let file_path = env::temp_dir().join(format!("workbook_{}_{name}", std::process::id()));
+    fs::write(&file_path, "workbook bytes").unwrap();
+    file_path
+}
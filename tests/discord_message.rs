@@ -0,0 +1,194 @@
+mod common;
+
+use common::{data, summary};
+use mockito::{Matcher, Server};
+use transaction_manager::discord_message::{
+    payload, term_summary, truncate, won, EmbedBuilder, CONTENT_LIMIT, EMBED_COUNT_LIMIT,
+    EMBED_LIMIT, FIELD_COUNT_LIMIT, FIELD_VALUE_LIMIT, TITLE_LIMIT,
+};
+use transaction_manager::models::budget::BudgetPlan;
+use transaction_manager::models::data::{BusinessType, Data};
+use transaction_manager::send_file::Webhook;
+use transaction_manager::summary::Summary;
+
+#[test]
+fn formats_won() {
+    assert_eq!(won(0), "0원");
+    assert_eq!(won(999), "999원");
+    assert_eq!(won(1000), "1,000원");
+    assert_eq!(won(1_234_567), "1,234,567원");
+    assert_eq!(won(-250_000), "-250,000원");
+}
+
+#[test]
+fn truncates_by_characters() {
+    assert_eq!(truncate("재정감사", 4), "재정감사");
+    assert_eq!(truncate("재정감사", 3), "재정…");
+    assert_eq!(truncate("abc", 0), "");
+}
+
+#[test]
+fn builder_respects_limits() {
+    let mut builder = EmbedBuilder::new();
+    builder.title("가".repeat(300));
+    for i in 0..30 {
+        builder.field(format!("필드 {i}"), "값".repeat(2000), false);
+    }
+    let embed = builder.build();
+
+    assert_eq!(embed.title.chars().count(), TITLE_LIMIT);
+    assert!(embed.title.ends_with('…'));
+    assert!(embed.fields.len() <= FIELD_COUNT_LIMIT);
+    assert!(embed
+        .fields
+        .iter()
+        .all(|field| field.value.chars().count() <= FIELD_VALUE_LIMIT));
+    assert!(embed.len() <= EMBED_LIMIT, "{}", embed.len());
+    // 제목 256자와 1,028자 필드 5개만 들어가고 나머지 25개는 개수만 표시
+    assert_eq!(embed.fields.len(), 6);
+    assert_eq!(embed.fields[4].name, "필드 4");
+    assert_eq!(embed.fields[5].value, "외 25개 항목 생략");
+}
+
+#[test]
+fn builder_keeps_small_embed() {
+    let embed = EmbedBuilder::new()
+        .title("제목")
+        .description("설명")
+        .color(0x123456)
+        .field("이름", "값", true)
+        .footer("바닥글")
+        .build();
+
+    assert_eq!(embed.title, "제목");
+    assert_eq!(embed.color, 0x123456);
+    assert_eq!(embed.fields.len(), 1);
+    assert_eq!(embed.len(), "제목설명이름값바닥글".chars().count());
+}
+
+#[test]
+fn field_count_overflow_is_summarised() {
+    let mut builder = EmbedBuilder::new();
+    for i in 0..40 {
+        builder.field(i.to_string(), "v", true);
+    }
+    let embed = builder.build();
+
+    assert_eq!(embed.fields.len(), FIELD_COUNT_LIMIT);
+    assert_eq!(embed.fields[FIELD_COUNT_LIMIT - 2].name, "23");
+    assert_eq!(embed.fields.last().unwrap().value, "외 16개 항목 생략");
+}
+
+#[test]
+fn term_summary_has_numbers_at_a_glance() {
+    let embed = term_summary("컴퓨터공학과 학생회", (2024, 2), &summary(), 4);
+    let field = |name: &str| {
+        embed
+            .fields
+            .iter()
+            .find(|field| field.name == name)
+            .map(|field| field.value.clone())
+            .unwrap_or_else(|| panic!("no field {name}"))
+    };
+
+    assert_eq!(embed.title, "2024년도 제2회기 재정 현황");
+    assert_eq!(embed.description, "컴퓨터공학과 학생회");
+    assert_eq!(field("기초 잔액"), "500,000원");
+    assert_eq!(field("총수입"), "1,000,000원");
+    assert_eq!(field("총지출"), "280,000원");
+    assert_eq!(field("기말 잔액"), "1,220,000원");
+    assert_eq!(field("미분류 거래"), "2건");
+    assert_eq!(field("감사 의견"), "4건");
+
+    let table = field("월별 현황");
+    assert!(table.starts_with("```\n") && table.ends_with("```"));
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 5, "{table}");
+    assert!(lines[2].starts_with("6월") && lines[2].ends_with("1,250,000원"));
+    assert!(lines[3].starts_with("7월") && lines[3].ends_with("1,220,000원"));
+}
+
+#[test]
+fn long_month_table_fits_field() {
+    let month_data_list: Vec<(u8, Vec<Data>)> = (0..80)
+        .map(|i| {
+            (
+                (i % 12 + 1) as u8,
+                vec![data(1, 123_456_789, 98_765_432, BusinessType::Unclassified)],
+            )
+        })
+        .collect();
+    let summary = Summary::new(&month_data_list, &BudgetPlan::default());
+    let embed = term_summary("학생회", (2024, 1), &summary, 0);
+    let table = &embed.fields.last().unwrap().value;
+
+    assert!(table.chars().count() <= FIELD_VALUE_LIMIT);
+    assert!(table.contains("개월"), "{table}");
+    assert!(table.ends_with("```"));
+}
+
+#[test]
+fn payload_respects_message_limits() {
+    let mut builder = EmbedBuilder::new();
+    builder.description("가".repeat(4000));
+    let embeds = vec![builder.build(); 12];
+    let payload = payload(&"a".repeat(3000), &embeds);
+
+    assert_eq!(
+        payload["content"].as_str().unwrap().chars().count(),
+        CONTENT_LIMIT
+    );
+    let embeds = payload["embeds"].as_array().unwrap();
+    assert!(embeds.len() <= EMBED_COUNT_LIMIT);
+    // 모든 embed 합계 6000자 이하
+    assert_eq!(embeds.len(), 1);
+}
+
+#[tokio::test]
+async fn sends_embed_with_workbook() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/webhook")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(
+                r#""embeds":\[\{"color":\d+,"description":"컴퓨터공학과 학생회""#.into(),
+            ),
+            Matcher::Regex(r#""name":"총지출","value":"280,000원""#.into()),
+            Matcher::Regex(r#""title":"2024년도 제2회기 재정 현황""#.into()),
+            Matcher::Regex(r#"filename="workbook_\d+_discord_message.xlsx""#.into()),
+        ]))
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let file_path = common::workbook("discord_message.xlsx");
+    let embed = term_summary("컴퓨터공학과 학생회", (2024, 2), &summary(), 4);
+    Webhook::new(format!("{}/webhook", server.url()), 0)
+        .send(&payload("재정감사", &[embed]), Some(&file_path))
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn sends_embed_without_file() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/webhook")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r#"name="payload_json""#.into()),
+            Matcher::Regex(r#""content":"요약""#.into()),
+        ]))
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let embed = term_summary("학생회", (2024, 1), &summary(), 0);
+    Webhook::new(format!("{}/webhook", server.url()), 0)
+        .send(&payload("요약", &[embed]), None)
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
@@ -0,0 +1,374 @@
+mod common;
+
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use common::{summary, workbook};
+use mockito::{Matcher, Server};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use transaction_manager::models::config::Config;
+use transaction_manager::models::notify::{
+    EmailConfig, NotifierConfig, Security, SlackConfig, WebhookConfig,
+};
+use transaction_manager::notify::{
+    notifiers, notifiers_with, notify_all, EmailNotifier, Notice, Notifier, SlackNotifier,
+    WebhookNotifier,
+};
+use transaction_manager::summary::Summary;
+
+fn notice<'a>(summary: &'a Summary, file_path: Option<&'a PathBuf>) -> Notice<'a> {
+    Notice {
+        organization: "컴퓨터공학과 학생회",
+        period: (2024, 2),
+        summary,
+        findings: 4,
+        file_path: file_path.map(PathBuf::as_path),
+    }
+}
+
+#[test]
+fn deserializes_notifier_configs() {
+    let config: Config = serde_json::from_str(
+        r#"{
+            "notifiers": [
+                { "type": "discord", "webhook_url": "http://discord" },
+                { "type": "slack", "webhook_url": "http://slack", "max_retries": 1 },
+                { "type": "webhook", "url": "http://hook", "headers": { "Authorization": "Bearer t" } },
+                { "type": "email", "host": "smtp.example.com", "security": "tls", "port": 465,
+                  "from": "audit@example.com", "to": ["treasurer@example.com"] }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.notifiers.len(), 4);
+    let NotifierConfig::Discord(discord) = &config.notifiers[0] else {
+        panic!("{:?}", config.notifiers[0]);
+    };
+    assert_eq!(discord.webhook_url.as_deref(), Some("http://discord"));
+    let NotifierConfig::Slack(slack) = &config.notifiers[1] else {
+        panic!("{:?}", config.notifiers[1]);
+    };
+    assert_eq!(slack.max_retries, 1);
+    let NotifierConfig::Webhook(webhook) = &config.notifiers[2] else {
+        panic!("{:?}", config.notifiers[2]);
+    };
+    assert_eq!(webhook.headers["Authorization"], "Bearer t");
+    assert_eq!(webhook.max_retries, 3);
+    let NotifierConfig::Email(email) = &config.notifiers[3] else {
+        panic!("{:?}", config.notifiers[3]);
+    };
+    assert_eq!(email.security, Security::Tls);
+    assert_eq!(email.port, 465);
+
+    // 기본값: STARTTLS, 587
+    let email: EmailConfig =
+        serde_json::from_str(r#"{ "host": "smtp", "from": "a@b.c", "to": [] }"#).unwrap();
+    assert_eq!(email.security, Security::StartTls);
+    assert_eq!(email.port, 587);
+}
+
+#[test]
+fn slack_payload_uses_blocks() {
+    let summary = summary();
+    let file_path = workbook("slack.xlsx");
+    let payload = SlackNotifier::payload(&notice(&summary, Some(&file_path)));
+
+    assert_eq!(
+        payload["text"],
+        "컴퓨터공학과 학생회 2024년도 제2회기 재정감사"
+    );
+    let blocks = payload["blocks"].as_array().unwrap();
+    assert_eq!(blocks[0]["type"], "header");
+    assert_eq!(blocks[1]["fields"][2]["text"], "*총지출*\n280,000원");
+    assert!(blocks[2]["text"]["text"]
+        .as_str()
+        .unwrap()
+        .contains("1,220,000원"));
+    assert_eq!(blocks[3]["type"], "context");
+}
+
+#[tokio::test]
+async fn sends_to_slack() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/slack")
+        .match_header("content-type", "application/json")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r#""type":"header""#.into()),
+            Matcher::Regex(r#"\*기말 잔액\*\\n1,220,000원"#.into()),
+        ]))
+        .with_status(200)
+        .with_body("ok")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let summary = summary();
+    SlackNotifier::new(SlackConfig {
+        webhook_url: format!("{}/slack", server.url()),
+        max_retries: 0,
+    })
+    .notify(&notice(&summary, None))
+    .await
+    .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn sends_json_webhook_with_headers() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/hook")
+        .match_header("authorization", "Bearer token")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "organization": "컴퓨터공학과 학생회",
+            "year": 2024,
+            "term": 2,
+            "opening_balance": 500_000,
+            "income": 1_000_000,
+            "expense": 280_000,
+            "balance": 1_220_000,
+            "unclassified": 2,
+            "findings": 4,
+        })))
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let summary = summary();
+    let file_path = workbook("hook.xlsx");
+    let notice = notice(&summary, Some(&file_path));
+    let payload = WebhookNotifier::payload(&notice);
+    assert_eq!(payload["months"].as_array().unwrap().len(), 2);
+    assert_eq!(payload["months"][1]["balance"], 1_220_000);
+    assert!(payload["workbook"].as_str().unwrap().ends_with("hook.xlsx"));
+
+    WebhookNotifier::new(WebhookConfig {
+        url: format!("{}/hook", server.url()),
+        headers: [("Authorization".to_owned(), "Bearer token".to_owned())].into(),
+        max_retries: 0,
+    })
+    .notify(&notice)
+    .await
+    .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn builds_notifiers_from_config() {
+    let mut server = Server::new_async().await;
+    let discord = server
+        .mock("POST", "/discord")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r#""content":"컴퓨터공학과 학생회 2024년도 제2회기 재정감사""#.into()),
+            Matcher::Regex(r#"filename="workbook_\d+_discord.xlsx""#.into()),
+        ]))
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+    let slack = server
+        .mock("POST", "/slack")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config: Config = serde_json::from_value(serde_json::json!({
+        "notifiers": [
+            { "type": "discord", "webhook_url": format!("{}/discord", server.url()), "max_retries": 0 },
+            { "type": "slack", "webhook_url": format!("{}/slack", server.url()), "max_retries": 0 },
+        ]
+    }))
+    .unwrap();
+    let notifiers = notifiers_with(&config, |_| None).unwrap();
+    let names: Vec<&str> = notifiers.iter().map(|notifier| notifier.name()).collect();
+    assert_eq!(names, ["discord", "slack"]);
+
+    let summary = summary();
+    let file_path = workbook("discord.xlsx");
+    let results = notify_all(&notifiers, &notice(&summary, Some(&file_path))).await;
+    assert!(
+        results.iter().all(|(_, result)| result.is_ok()),
+        "{results:?}"
+    );
+    discord.assert_async().await;
+    slack.assert_async().await;
+}
+
+// DISCORD_WEBHOOK_URL은 config의 discord에만 사용 (같은 webhook으로 두 번 보내지 않음)
+#[test]
+fn webhook_env_applies_to_config_discord_only() {
+    let env = |_: &str| Some("http://127.0.0.1:9/env".to_owned());
+    let config = Config::default();
+    let names: Vec<&str> = notifiers_with(&config, env)
+        .unwrap()
+        .iter()
+        .map(|notifier| notifier.name())
+        .collect();
+    assert_eq!(names, ["discord"]);
+
+    let config: Config =
+        serde_json::from_str(r#"{ "notifiers": [{ "type": "discord" }] }"#).unwrap();
+    let error = notifiers_with(&config, env).err().unwrap();
+    assert!(error.to_string().contains("no webhook_url"), "{error}");
+}
+
+#[test]
+fn invalid_notifier_config_is_an_error() {
+    let config: Config = serde_json::from_str(
+        r#"{ "notifiers": [{ "type": "email", "host": "localhost", "from": "a@b.c", "to": [] }] }"#,
+    )
+    .unwrap();
+    let error = notifiers(&config).err().unwrap();
+    assert!(error.to_string().contains("no recipients"), "{error}");
+}
+
+struct Failing;
+
+#[async_trait]
+impl Notifier for Failing {
+    fn name(&self) -> &'static str {
+        "failing"
+    }
+
+    async fn notify(&self, _notice: &Notice<'_>) -> Result<(), Box<dyn std::error::Error>> {
+        Err("unreachable".into())
+    }
+}
+
+#[tokio::test]
+async fn failure_does_not_stop_other_notifiers() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/hook")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let notifiers: Vec<Box<dyn Notifier>> = vec![
+        Box::new(Failing),
+        Box::new(WebhookNotifier::new(WebhookConfig {
+            url: format!("{}/hook", server.url()),
+            headers: Default::default(),
+            max_retries: 0,
+        })),
+    ];
+    let summary = summary();
+    let results = notify_all(&notifiers, &notice(&summary, None)).await;
+
+    assert_eq!(results[0], ("failing", Err("unreachable".to_owned())));
+    assert_eq!(results[1], ("webhook", Ok(())));
+    mock.assert_async().await;
+}
+
+fn email_config(port: u16) -> EmailConfig {
+    EmailConfig {
+        host: "127.0.0.1".to_owned(),
+        port,
+        security: Security::None,
+        username: None,
+        password: None,
+        from: "중앙감사위원회 <audit@example.com>".to_owned(),
+        to: vec!["treasurer@example.com".to_owned()],
+    }
+}
+
+#[tokio::test]
+async fn email_message_has_summary_and_workbook() {
+    let summary = summary();
+    let file_path = workbook("mail.xlsx");
+    let message = EmailNotifier::new(&email_config(25))
+        .unwrap()
+        .message(&notice(&summary, Some(&file_path)))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        message.headers().get_raw("Subject"),
+        Some("컴퓨터공학과 학생회 2024년도 제2회기 재정감사")
+    );
+    let formatted = String::from_utf8(message.formatted()).unwrap();
+    assert!(formatted.contains("multipart/mixed"), "{formatted}");
+    assert!(formatted.contains(
+        "Content-Type: application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+    ));
+
+    let text = notice(&summary, None).text();
+    assert!(text.contains("총지출: 280,000원"), "{text}");
+    assert!(text.contains("월별 현황"), "{text}");
+}
+
+// SMTP 대화를 기록하는 서버 (한 번 연결, 응답은 모두 성공)
+async fn smtp_server() -> (u16, tokio::task::JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        let mut received = Vec::new();
+        writer.write_all(b"220 localhost ESMTP\r\n").await.unwrap();
+        let mut data = false;
+        while let Some(line) = lines.next_line().await.unwrap() {
+            received.push(line.clone());
+            let reply: &[u8] = if data {
+                if line != "." {
+                    continue;
+                }
+                data = false;
+                b"250 queued\r\n"
+            } else if line.starts_with("EHLO") {
+                b"250 localhost\r\n"
+            } else if line == "DATA" {
+                data = true;
+                b"354 end with .\r\n"
+            } else if line == "QUIT" {
+                writer.write_all(b"221 bye\r\n").await.unwrap();
+                break;
+            } else {
+                b"250 ok\r\n"
+            };
+            writer.write_all(reply).await.unwrap();
+        }
+        received
+    });
+    (port, handle)
+}
+
+#[tokio::test]
+async fn sends_email_with_attachment() {
+    let (port, server) = smtp_server().await;
+    let summary = summary();
+    let file_path = workbook("smtp.xlsx");
+    let notifier = EmailNotifier::new(&email_config(port)).unwrap();
+    notifier
+        .notify(&notice(&summary, Some(&file_path)))
+        .await
+        .unwrap();
+    drop(notifier);
+
+    let received = server.await.unwrap();
+    assert!(
+        received.contains(&"MAIL FROM:<audit@example.com>".to_owned()),
+        "{received:?}"
+    );
+    assert!(
+        received.contains(&"RCPT TO:<treasurer@example.com>".to_owned()),
+        "{received:?}"
+    );
+    let message = received.join("\n");
+    assert!(message.contains("To: treasurer@example.com"), "{message}");
+    assert!(
+        message.contains(&format!(
+            "filename=\"workbook_{}_smtp.xlsx\"",
+            std::process::id()
+        )),
+        "{message}"
+    );
+    assert!(message.contains("workbook bytes"), "{message}");
+}
@@ -0,0 +1,216 @@
+mod common;
+
+use std::env;
+
+use common::workbook;
+use mockito::{Matcher, Server};
+use transaction_manager::models::discord::DiscordConfig;
+use transaction_manager::send_file::{send_discord_xlsx, Webhook, WEBHOOK_ENV};
+
+#[tokio::test]
+async fn uploads_file_with_message() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/webhook")
+        .match_header(
+            "content-type",
+            Matcher::Regex("^multipart/form-data; boundary=".into()),
+        )
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r#"name="payload_json"\s+\{"content":"2024 재정감사"\}"#.into()),
+            Matcher::Regex(r#"name="files\[0\]"; filename="workbook_\d+_upload.xlsx""#.into()),
+            Matcher::Regex("workbook bytes".into()),
+        ]))
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let file_path = workbook("upload.xlsx");
+    Webhook::new(format!("{}/webhook", server.url()), 0)
+        .send_file(&file_path, "2024 재정감사")
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn retries_after_rate_limit() {
+    let mut server = Server::new_async().await;
+    let limited = server
+        .mock("POST", "/webhook")
+        .with_status(429)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"message": "You are being rate limited.", "retry_after": 0.05, "global": false}"#,
+        )
+        .expect(2)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("POST", "/webhook")
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let file_path = workbook("retry.xlsx");
+    Webhook::new(format!("{}/webhook", server.url()), 2)
+        .send_file(&file_path, "retry")
+        .await
+        .unwrap();
+    limited.assert_async().await;
+    ok.assert_async().await;
+}
+
+#[tokio::test]
+async fn retries_server_errors_with_retry_after_header() {
+    let mut server = Server::new_async().await;
+    let unavailable = server
+        .mock("POST", "/webhook")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+    let limited = server
+        .mock("POST", "/webhook")
+        .with_status(429)
+        .with_header("retry-after", "0")
+        .expect(1)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("POST", "/webhook")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let file_path = workbook("server_error.xlsx");
+    Webhook::new(format!("{}/webhook", server.url()), 3)
+        .send_file(&file_path, "server error")
+        .await
+        .unwrap();
+    unavailable.assert_async().await;
+    limited.assert_async().await;
+    ok.assert_async().await;
+}
+
+#[tokio::test]
+async fn gives_up_after_max_retries() {
+    let mut server = Server::new_async().await;
+    let limited = server
+        .mock("POST", "/webhook")
+        .with_status(429)
+        .with_body(r#"{"retry_after": 0.01}"#)
+        .expect(3)
+        .create_async()
+        .await;
+
+    let file_path = workbook("give_up.xlsx");
+    let error = Webhook::new(format!("{}/webhook", server.url()), 2)
+        .send_file(&file_path, "give up")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("after 3 attempts"), "{error}");
+    limited.assert_async().await;
+}
+
+#[tokio::test]
+async fn does_not_retry_client_errors() {
+    let mut server = Server::new_async().await;
+    let rejected = server
+        .mock("POST", "/webhook")
+        .with_status(400)
+        .with_body(r#"{"message": "Cannot send an empty message"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let file_path = workbook("rejected.xlsx");
+    let error = Webhook::new(format!("{}/webhook", server.url()), 3)
+        .send_file(&file_path, "")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("400"), "{error}");
+    rejected.assert_async().await;
+}
+
+#[tokio::test]
+async fn gives_up_without_waiting_when_retries_are_exhausted() {
+    let mut server = Server::new_async().await;
+    let limited = server
+        .mock("POST", "/webhook")
+        .with_status(429)
+        .with_body(r#"{"retry_after": 86400}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let file_path = workbook("long_wait.xlsx");
+    let error = Webhook::new(format!("{}/webhook", server.url()), 0)
+        .send_file(&file_path, "long wait")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("after 1 attempts"), "{error}");
+    limited.assert_async().await;
+}
+
+// 환경 변수를 바꾸지 않고 lookup으로 DISCORD_WEBHOOK_URL 대신
+#[tokio::test]
+async fn webhook_url_from_config_or_env() {
+    let mut server = Server::new_async().await;
+    let from_config = server
+        .mock("POST", "/config")
+        .with_status(200)
+        .expect(2)
+        .create_async()
+        .await;
+    let from_env = server
+        .mock("POST", "/env")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+    let env_url = format!("{}/env", server.url());
+    let env = |name: &str| (name == WEBHOOK_ENV).then(|| env_url.clone());
+    let file_path = workbook("config.xlsx");
+
+    let config = DiscordConfig::default();
+    assert!(Webhook::from_config_with(&config, |_| None).is_none());
+    assert!(Webhook::from_config_with(&config, |_| Some(" ".to_owned())).is_none());
+    Webhook::from_config_with(&config, env)
+        .unwrap()
+        .send_file(&file_path, "env")
+        .await
+        .unwrap();
+
+    // config가 환경 변수보다 우선
+    let config = DiscordConfig {
+        webhook_url: Some(format!("{}/config", server.url())),
+        ..DiscordConfig::default()
+    };
+    assert!(send_discord_xlsx(&config, &file_path, "config")
+        .await
+        .unwrap());
+    Webhook::from_config_with(&config, env)
+        .unwrap()
+        .send_file(&file_path, "config")
+        .await
+        .unwrap();
+
+    from_config.assert_async().await;
+    from_env.assert_async().await;
+}
+
+#[tokio::test]
+async fn missing_file_is_an_error() {
+    let error = Webhook::new("http://127.0.0.1:9/webhook", 0)
+        .send_file(&env::temp_dir().join("send_file_missing.xlsx"), "missing")
+        .await
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("send_file_missing.xlsx"),
+        "{error}"
+    );
+}
@@ -0,0 +1,159 @@
+#![cfg(feature = "server")]
+
+use std::io::Cursor;
+
+use calamine::{Reader, Xlsx};
+use reqwest::multipart::{Form, Part};
+use reqwest::{header, Client, StatusCode};
+use serde_json::Value;
+use tokio::net::TcpListener;
+use transaction_manager::models::config::Config;
+use transaction_manager::send_file::XLSX_MIME;
+use transaction_manager::server;
+
+// 6월 수입 1,000,000, 7월 지출 30,000 (최근 거래가 앞)
+const STATEMENT: &str = "\
+2|2024.07.10 14:00:00|문구점|30,000|0|1,470,000|문구점|모바일|info|
+1|2024.06.03 10:00:00|홍길동|0|1,000,000|1,500,000|홍길동|모바일|info|
+";
+const OUT_OF_TERM: &str = "\
+1|2024.03.15 10:00:00|홍길동|0|1,000,000|1,000,000|홍길동|모바일|info|
+";
+const PROFILE: &str = r#"{ "organization": "전자공학과 학생회", "ledger": false }"#;
+const BUDGET: &str = r#"{ "carry_over": 500000 }"#;
+const UNCLASSIFIED_BUDGET: &str =
+    r#"{ "businesses": [{ "business_type": "미정", "name": "간식 행사" }] }"#;
+
+// 임의의 port로 server 시작, 주소 반환
+async fn start() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { server::serve(listener, Config::default()).await.unwrap() });
+    format!("http://{address}")
+}
+
+fn file(name: &str, content: &str) -> Part {
+    Part::bytes(content.as_bytes().to_vec()).file_name(name.to_owned())
+}
+
+fn upload() -> Form {
+    Form::new()
+        .part("statement", file("account.txt", STATEMENT))
+        .part("profile", file("profile.json", PROFILE))
+        .part("budget", file("budget.json", BUDGET))
+}
+
+async fn post(url: String, form: Form) -> reqwest::Response {
+    Client::new()
+        .post(url)
+        .multipart(form)
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn returns_workbook_for_upload() {
+    let address = start().await;
+    let response = post(format!("{address}/workbook"), upload()).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], XLSX_MIME);
+    let disposition = response.headers()[header::CONTENT_DISPOSITION]
+        .to_str()
+        .unwrap()
+        .to_owned();
+    assert!(disposition.starts_with("attachment; "), "{disposition}");
+    // "2024 중앙감사위원회_재정감사.xlsx"
+    assert!(
+        disposition.contains("filename*=UTF-8''2024%20%EC%A4%91%EC%95%99"),
+        "{disposition}"
+    );
+
+    let bytes = response.bytes().await.unwrap();
+    let workbook = Xlsx::new(Cursor::new(bytes.to_vec())).unwrap();
+    let sheet_names = workbook.sheet_names();
+    assert!(sheet_names.contains(&"2024년도 제2회기 예산안".to_owned()));
+    assert!(
+        sheet_names.contains(&"7월 정산서".to_owned()),
+        "{sheet_names:?}"
+    );
+    // profile의 ledger: false
+    assert!(!sheet_names.iter().any(|name| name.ends_with("사업별 장부")));
+}
+
+#[tokio::test]
+async fn returns_summary_json() {
+    let address = start().await;
+    // 같은 거래내역을 두 번 올려도 한 번만 포함
+    let form = upload().part("statement", file("again.txt", STATEMENT));
+    let response = post(format!("{address}/summary"), form).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let summary: Value = response.json().await.unwrap();
+    assert_eq!(summary["organization"], "전자공학과 학생회");
+    assert_eq!(summary["year"], 2024);
+    assert_eq!(summary["term"], 2);
+    assert_eq!(summary["opening_balance"], 500_000);
+    assert_eq!(summary["income"], 1_000_000);
+    assert_eq!(summary["expense"], 30_000);
+    assert_eq!(summary["balance"], 1_470_000);
+    assert_eq!(summary["unclassified"], 2);
+    assert_eq!(summary["workbook"], "2024 중앙감사위원회_재정감사.xlsx");
+    let opinions = summary["opinions"].as_array().unwrap();
+    assert_eq!(opinions.len() as u64, summary["findings"].as_u64().unwrap());
+    assert!(opinions
+        .iter()
+        .any(|opinion| opinion["check"] == "영수증 누락" && opinion["sheet"] == "7월 정산서"));
+}
+
+#[tokio::test]
+async fn rejects_invalid_uploads() {
+    let address = start().await;
+    let cases = [
+        (
+            Form::new().part("profile", file("profile.json", PROFILE)),
+            StatusCode::BAD_REQUEST,
+            "statement is required",
+        ),
+        (
+            Form::new().part("statement", file("empty.txt", "no transactions\n")),
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "no transactions in statements",
+        ),
+        (
+            // 회기는 1월 또는 6월에 시작
+            Form::new().part("statement", file("march.txt", OUT_OF_TERM)),
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "transactions start in 2024.03",
+        ),
+        (
+            Form::new()
+                .part("statement", file("account.txt", STATEMENT))
+                .part("budget", file("budget.json", "{ not json")),
+            StatusCode::BAD_REQUEST,
+            "budget: ",
+        ),
+        (
+            // 예산안에 작성할 수 없는 미정 사업
+            Form::new()
+                .part("statement", file("account.txt", STATEMENT))
+                .part("budget", file("budget.json", UNCLASSIFIED_BUDGET)),
+            StatusCode::BAD_REQUEST,
+            "budget: 사업 '간식 행사'의 사업구분이 미정입니다.",
+        ),
+        (
+            Form::new().part("receipt", file("receipt.png", "")),
+            StatusCode::BAD_REQUEST,
+            "unknown field receipt",
+        ),
+    ];
+
+    for (form, status, message) in cases {
+        let response = post(format!("{address}/workbook"), form).await;
+        assert_eq!(response.status(), status);
+        let error: Value = response.json().await.unwrap();
+        let error = error["error"].as_str().unwrap();
+        assert!(error.starts_with(message), "{error}");
+    }
+}
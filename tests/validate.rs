@@ -0,0 +1,36 @@
+mod common;
+
+use std::env;
+
+use transaction_manager::generate::generate;
+use transaction_manager::models::config::Config;
+use transaction_manager::models::data::BusinessType;
+use transaction_manager::validate::{validate, Scope};
+
+// 작성 직후 workbook은 구조 검증을 통과하고, 영수증 번호만 남음
+#[tokio::test]
+async fn fresh_workbook_passes_structure() {
+    let location = env::temp_dir().join(format!("validate_{}_fresh.xlsx", std::process::id()));
+    let table = vec![
+        common::data(7, 0, 30_000, BusinessType::Unclassified),
+        common::data(6, 0, 250_000, BusinessType::GeneralBusiness),
+        common::data(6, 1_000_000, 0, BusinessType::Unclassified),
+    ];
+    let config = Config {
+        preserve_edits: false,
+        ..Config::default()
+    };
+    generate(&config, table, Some(&location)).await.unwrap();
+
+    let report = validate(&location, Scope::Structure).unwrap();
+    assert!(report.passed(), "{report}");
+
+    let report = validate(&location, Scope::All).unwrap();
+    let failed: Vec<_> = report
+        .checks
+        .iter()
+        .filter(|check| !check.passed())
+        .map(|check| (check.name, check.issues.len()))
+        .collect();
+    assert_eq!(failed, [("영수증 번호", 2)]);
+}